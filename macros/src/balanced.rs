@@ -0,0 +1,125 @@
+//! Balanced (DC-free) codes
+//!
+//! Every discriminant has exactly half of its bits set. The Manchester sub-mode expands each bit of
+//! the variant index to a pair of bits, `01` for a `0` and `10` for a `1`, so every pair is
+//! balanced as well.
+
+use std::fmt::Display;
+
+use darling::FromMeta;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Balanced {
+    manchester: bool,
+}
+
+impl Balanced {
+    /// Manchester codes count through the variant indices, plain balanced codes count through
+    /// the values directly
+    pub const fn is_manchester(self) -> bool {
+        self.manchester
+    }
+
+    /// the number of codewords that fit in `bits` bits, or `None` if that does not fit in a `u128`
+    pub fn capacity(self, bits: u32) -> Option<u128> {
+        if self.manchester {
+            return Some(1 << (bits / 2));
+        }
+
+        // `bits` choose `bits / 2`
+        (1..=u128::from(bits / 2)).try_fold(1_u128, |capacity, idx| {
+            Some(capacity.checked_mul(u128::from(bits / 2) + idx)? / idx)
+        })
+    }
+
+    pub fn encode(self, cursor: u128, bits: u32) -> Option<u128> {
+        if !self.manchester {
+            return Some(cursor);
+        }
+        if cursor.checked_shr(bits / 2).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        Some((0..bits / 2).fold(0, |code, bit| {
+            let pair = if cursor >> bit & 1 == 1 { 0b10 } else { 0b01 };
+            code | pair << (2 * bit)
+        }))
+    }
+
+    /// the index of `code`, or `None` if it is not a valid codeword
+    pub fn decode(self, code: u128, bits: u32) -> Option<u128> {
+        if !self.manchester {
+            return (code.count_ones() == bits / 2).then_some(code);
+        }
+
+        (0..bits / 2).try_fold(0, |index, bit| match code >> (2 * bit) & 0b11 {
+            0b01 => Some(index),
+            0b10 => Some(index | 1 << bit),
+            _ => None,
+        })
+    }
+}
+
+impl FromMeta for Balanced {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct BalancedOptions {
+            manchester: darling::util::Flag,
+        }
+
+        let BalancedOptions { manchester } = BalancedOptions::from_list(items)?;
+        Ok(Self {
+            manchester: manchester.is_present(),
+        })
+    }
+}
+
+impl Display for Balanced {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.manchester {
+            write!(f, "balanced(manchester)")
+        } else {
+            write!(f, "balanced")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Balanced;
+
+    const MANCHESTER: Balanced = Balanced { manchester: true };
+
+    #[test]
+    pub fn manchester_u8() {
+        let codes = (0..4).map(|index| MANCHESTER.encode(index, 8).unwrap());
+
+        assert!(codes.eq([0x55, 0x56, 0x59, 0x5a]));
+        assert_eq!(MANCHESTER.encode(16, 8), None);
+    }
+
+    #[test]
+    pub fn manchester_round_trip() {
+        for bits in [8, 16, 128] {
+            for index in [0, 1, 0x0a, (1 << (bits / 2)) - 1] {
+                let code = MANCHESTER.encode(index, bits).unwrap();
+                assert_eq!(code.count_ones(), bits / 2);
+                assert_eq!(MANCHESTER.decode(code, bits), Some(index));
+            }
+        }
+        // balanced, but `00` and `11` are not valid pairs
+        assert_eq!(MANCHESTER.decode(0x0f, 8), None);
+    }
+
+    #[test]
+    pub fn capacity() {
+        assert_eq!(Balanced::default().capacity(8), Some(70));
+        assert_eq!(Balanced::default().capacity(16), Some(12870));
+        assert_eq!(Balanced::default().capacity(128), None);
+        assert_eq!(MANCHESTER.capacity(8), Some(16));
+    }
+}
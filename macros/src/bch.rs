@@ -0,0 +1,219 @@
+//! Binary BCH codes
+//!
+//! The code is the primitive narrow-sense BCH code of length `2^m - 1`, for the largest `m` that
+//! fits in the repr, so the top bit of the repr is left clear. Its generator polynomial `g` has
+//! `α, α^2, .., α^2t` as roots, where `α` is a root of a fixed primitive polynomial of degree `m`.
+//! The variant index is in the high bits, followed by the remainder of the index modulo `g` in the
+//! low bits, as with a CRC. Any two codewords differ in at least `2t + 1` bits, so up to `t`
+//! flipped bits can be corrected.
+
+use std::fmt::Display;
+
+use darling::FromMeta;
+
+/// A primitive polynomial of each degree, including the `x^m` term
+const PRIMITIVE: [u32; 8] = [0, 0b11, 0b111, 0b1011, 0x13, 0x25, 0x43, 0x89];
+
+/// A BCH code correcting `t` flipped bits
+#[derive(Copy, Clone, Debug)]
+pub struct Bch {
+    t: u32,
+}
+
+/// the degree of the field, and so the length of the code, that fits in `bits` bits
+///
+/// This is at most 7, since no repr is wider than 128 bits.
+const fn order(bits: u32) -> u32 {
+    (bits + 1).ilog2()
+}
+
+/// the number of bits in the code that fits in `bits` bits
+pub const fn length(bits: u32) -> u32 {
+    (1 << order(bits)) - 1
+}
+
+impl Bch {
+    pub const fn t(self) -> u32 {
+        self.t
+    }
+
+    /// The generator polynomial for `bits` bits, with bit `i` as the coefficient of `x^i`
+    fn generator(self, bits: u32) -> u128 {
+        let order = order(bits);
+        let len = length(bits);
+
+        // powers of `α`, as polynomials over GF(2) reduced by the primitive polynomial
+        let mut exp = Vec::with_capacity(len as usize);
+        let mut elem = 1_u32;
+        for _ in 0..len {
+            exp.push(elem);
+            elem <<= 1;
+            if elem >> order & 1 == 1 {
+                elem ^= PRIMITIVE[order as usize];
+            }
+        }
+        let mul = |a: u32, b: u32| -> u32 {
+            if a == 0 || b == 0 {
+                return 0;
+            }
+            let log = |elem| exp.iter().position(|&e| e == elem).unwrap_or_default();
+            exp[(log(a) + log(b)) % len as usize]
+        };
+
+        // every power of `α` that is a root of `g`, closed under squaring so that `g` is binary
+        let mut roots = Vec::new();
+        for power in 1..=(2 * self.t).min(len) {
+            let mut conjugate = power % len;
+            while !roots.contains(&conjugate) {
+                roots.push(conjugate);
+                conjugate = conjugate * 2 % len;
+            }
+        }
+
+        // the product of `x - α^root`, with coefficients in GF(2^m)
+        let mut poly = vec![1_u32];
+        for root in roots {
+            let root = exp[root as usize];
+            let mut next = vec![0; poly.len() + 1];
+            for (deg, &coeff) in poly.iter().enumerate() {
+                next[deg + 1] ^= coeff;
+                next[deg] ^= mul(coeff, root);
+            }
+            poly = next;
+        }
+
+        poly.iter().enumerate().fold(0, |gen_poly, (deg, &coeff)| {
+            gen_poly | u128::from(coeff) << deg
+        })
+    }
+
+    /// the number of check bits in a `bits` bit code
+    fn check_bits(self, bits: u32) -> u32 {
+        u128::BITS - 1 - self.generator(bits).leading_zeros()
+    }
+
+    /// the number of index bits in a `bits` bit code
+    fn data_bits(self, bits: u32) -> u32 {
+        length(bits) - self.check_bits(bits)
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub fn capacity(self, bits: u32) -> u128 {
+        1 << self.data_bits(bits)
+    }
+
+    /// the remainder of `index << check_bits` modulo the generator polynomial
+    fn checksum(self, index: u128, bits: u32) -> u128 {
+        let gen_poly = self.generator(bits);
+        let check_bits = self.check_bits(bits);
+        let mut rem = index << check_bits;
+        for bit in (check_bits..length(bits)).rev() {
+            if rem >> bit & 1 == 1 {
+                rem ^= gen_poly << (bit - check_bits);
+            }
+        }
+        rem
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        if index.checked_shr(self.data_bits(bits)).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        Some(index << self.check_bits(bits) | self.checksum(index, bits))
+    }
+
+    /// the index of `code`, or `None` if it is not a valid codeword
+    pub fn decode(self, code: u128, bits: u32) -> Option<u128> {
+        let index = code >> self.check_bits(bits);
+        (self.encode(index, bits) == Some(code)).then_some(index)
+    }
+}
+
+impl FromMeta for Bch {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct BchOptions {
+            t: u32,
+        }
+
+        let BchOptions { t } = BchOptions::from_list(items)?;
+        if t == 0 {
+            return Err(darling::Error::custom("BCH `t` must be at least 1"));
+        }
+
+        Ok(Self { t })
+    }
+}
+
+impl Display for Bch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bch(t = {})", self.t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+    use syn::parse_quote;
+
+    use super::{Bch, length};
+
+    fn bch(t: u32) -> Bch {
+        Bch { t }
+    }
+
+    #[test]
+    pub fn known_generators() {
+        // the (15, 7) code, g = x^8 + x^7 + x^6 + x^4 + 1
+        assert_eq!(bch(2).generator(16), 0x1d1);
+        // the (31, 21) code, g = x^10 + x^9 + x^8 + x^6 + x^5 + x^3 + 1
+        assert_eq!(bch(2).generator(32), 0x769);
+        // the (7, 4) Hamming code
+        assert_eq!(bch(1).generator(8), 0b1011);
+    }
+
+    #[test]
+    pub fn data_bits_per_repr() {
+        assert_eq!(length(8), 7);
+        assert_eq!(length(128), 127);
+        assert_eq!(bch(2).data_bits(16), 7);
+        assert_eq!(bch(2).data_bits(32), 21);
+        assert_eq!(bch(2).data_bits(64), 51);
+        assert_eq!(bch(3).data_bits(64), 45);
+        assert_eq!(bch(2).data_bits(128), 113);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for bits in [16, 32, 64, 128] {
+            let bch = bch(2);
+            for index in [0, 1, 2, 0x5a, (1 << bch.data_bits(bits)) - 1] {
+                let code = bch.encode(index, bits).unwrap();
+                assert_eq!(bch.decode(code, bits), Some(index));
+                assert_eq!(bch.decode(code ^ 0b100, bits), None);
+            }
+        }
+    }
+
+    #[test]
+    pub fn minimum_distance_u16() {
+        for t in 1..=3 {
+            let bch = bch(t);
+            let codes = (0..bch.capacity(16))
+                .map(|index| bch.encode(index, 16).unwrap())
+                .collect::<Vec<_>>();
+            for (idx, a) in codes.iter().enumerate() {
+                for b in &codes[..idx] {
+                    assert!((a ^ b).count_ones() > 2 * t, "{a:#x} {b:#x}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn zero_t() {
+        assert!(Bch::from_meta(&parse_quote!(bch(t = 0))).is_err());
+        assert_eq!(Bch::from_meta(&parse_quote!(bch(t = 2))).unwrap().t(), 2);
+    }
+}
@@ -0,0 +1,83 @@
+//! Berger codes
+//!
+//! The data bits hold the variant index, and the check bits above them hold the number of zeros
+//! in the data bits. Flipping bits in only one direction always changes one count but not the
+//! other, so every unidirectional error is detected.
+
+/// the number of check bits needed to count the zeros in `data_bits` bits
+pub const fn check_bits(data_bits: u32) -> u32 {
+    u32::BITS - data_bits.leading_zeros()
+}
+
+/// the largest number of data bits that fit alongside their check bits in `bits` bits
+pub const fn data_bits(bits: u32) -> u32 {
+    let mut data_bits = bits;
+    while data_bits + check_bits(data_bits) > bits {
+        data_bits -= 1;
+    }
+    data_bits
+}
+
+/// the number of codewords that fit in `bits` bits
+pub const fn capacity(bits: u32) -> u128 {
+    1 << data_bits(bits)
+}
+
+pub fn encode(index: u128, bits: u32) -> Option<u128> {
+    let data_bits = data_bits(bits);
+    if index >> data_bits != 0 {
+        return None;
+    }
+
+    let zeros = data_bits - index.count_ones();
+    Some(index | u128::from(zeros) << data_bits)
+}
+
+/// the index of `code`, or `None` if it is not a valid codeword
+pub fn decode(code: u128, bits: u32) -> Option<u128> {
+    let data_bits = data_bits(bits);
+    if code
+        .checked_shr(data_bits + check_bits(data_bits))
+        .unwrap_or(0)
+        != 0
+    {
+        return None;
+    }
+
+    let index = code & ((1 << data_bits) - 1);
+    let zeros = code >> data_bits;
+    (zeros == u128::from(data_bits - index.count_ones())).then_some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{data_bits, decode, encode};
+
+    #[test]
+    pub fn data_bits_per_repr() {
+        assert_eq!(data_bits(8), 5);
+        assert_eq!(data_bits(16), 12);
+        assert_eq!(data_bits(32), 27);
+        assert_eq!(data_bits(64), 58);
+        assert_eq!(data_bits(128), 121);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for bits in [8, 16, 128] {
+            for index in [0, 1, 2, 0x15, (1 << data_bits(bits)) - 1] {
+                let code = encode(index, bits).unwrap();
+                assert_eq!(decode(code, bits), Some(index));
+            }
+        }
+    }
+
+    #[test]
+    pub fn unidirectional_errors_detected() {
+        let code = encode(0x15, 8).unwrap();
+        // clear some bits, in both the data and check bits
+        for mask in [0x01_u128, 0x05, 0x40, 0x81, 0xff] {
+            assert_eq!(decode(code & !mask, 8), None, "{mask:#x}");
+        }
+    }
+}
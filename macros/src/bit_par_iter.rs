@@ -0,0 +1,834 @@
+#![expect(
+    clippy::cast_possible_truncation,
+    reason = "all occurrences of casting are 1"
+)]
+
+use itertools::Itertools;
+
+use crate::{Correcting, Parity, parity_check::ParityCheck};
+
+/// the number of distance checks that counting the remaining values may take, before giving up
+const COUNT_BUDGET: u128 = 1 << 22;
+
+/// the number of distance checks that assigning every value may take, before giving up
+const SEARCH_BUDGET: u128 = 1 << 26;
+
+pub trait IntegerParity: Copy + Sized {
+    const BITS: u32;
+    /// every width the repr may have on the target, which is only more than `BITS` for `usize`
+    /// and `isize`
+    const TARGET_BITS: &'static [u32] = &[Self::BITS];
+    fn zero() -> Self;
+    fn checked_increment(self) -> Option<Self>;
+    fn has_parity(self, parity: &Parity) -> bool;
+    /// the smallest value that is not less than `self` and has the given parity
+    fn seek(self, parity: &Parity) -> Option<Self>;
+    /// the number of bits that differ between `self` and `other`
+    fn distance(self, other: Self) -> u32;
+    /// the bits of `self`, zero extended
+    fn to_bits(self) -> u128;
+    /// the lowest `Self::BITS` bits of `bits`
+    fn from_bits(bits: u128) -> Self;
+
+    /// Whether every bit is cleared or every bit is set, which is what a dead bus or erased flash
+    /// reads as, at any width the repr may have on the target
+    fn is_stuck(self) -> bool {
+        let bits = self.to_bits();
+        bits == 0
+            || Self::TARGET_BITS
+                .iter()
+                .any(|&width| bits == u128::MAX >> (u128::BITS - width))
+    }
+}
+
+/// Every value that [`IntegerParity::is_stuck`] holds for, at the width of the host
+fn stuck_values<T>() -> impl Iterator<Item = T>
+where
+    T: IntegerParity,
+{
+    T::TARGET_BITS
+        .iter()
+        .filter(|&&width| width <= T::BITS)
+        .map(|&width| T::from_bits(u128::MAX >> (u128::BITS - width)))
+        .chain([T::zero()])
+}
+
+/// How many more values fit, as counted by [`BitParityIter::remaining`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Remaining {
+    Exact(u128),
+    /// the search gave up after this many, so more may fit
+    AtLeast(u128),
+}
+
+#[derive(Clone)]
+pub struct BitParityIter<T>
+where
+    T: IntegerParity,
+{
+    /// the previous position in the code
+    ///
+    /// This is the value itself, unless the parity is systematic, in which case it is the index
+    /// that gets encoded.
+    ///
+    /// `None` if this is the first iteration, `Some` otherwise
+    prev_val: Option<T>,
+
+    parity: Option<Parity>,
+
+    /// the minimum Hamming distance between any two values in `codebook`
+    ///
+    /// `None` if only the parity is enforced
+    min_distance: Option<u32>,
+
+    /// whether the all-zeros and all-ones values are skipped
+    exclude_stuck: bool,
+
+    /// the stuck value that a parity with a fixed codeword per index stopped at, see
+    /// [`Self::stuck`]
+    stuck: Option<T>,
+
+    /// the number of high bits, and the tag that every value has in them
+    tag: Option<(u32, u128)>,
+
+    /// every value that has been yielded or reserved so far
+    codebook: Vec<T>,
+
+    /// the distance checks left before [`Self::next_constrained`] gives up
+    budget: u128,
+}
+
+impl<T> BitParityIter<T>
+where
+    T: IntegerParity,
+{
+    pub(crate) const fn new(parity: Option<Parity>, min_distance: Option<u32>) -> Self {
+        Self {
+            prev_val: None,
+            parity,
+            min_distance,
+            exclude_stuck: false,
+            stuck: None,
+            tag: None,
+            codebook: Vec::new(),
+            budget: SEARCH_BUDGET,
+        }
+    }
+
+    /// Skips the all-zeros and all-ones values if `exclude_stuck` is set
+    ///
+    /// A parity that gives each index a fixed codeword cannot skip one without moving every later
+    /// variant off its index, so it stops at a stuck value instead.
+    pub(crate) const fn excluding_stuck(mut self, exclude_stuck: bool) -> Self {
+        self.exclude_stuck = exclude_stuck;
+        self
+    }
+
+    /// Only yields values with `tag` in their high `tag_bits` bits
+    ///
+    /// Those are a contiguous range, so this only supports parities that count through the values
+    /// directly.
+    pub(crate) const fn tagged(mut self, tag: Option<(u32, u128)>) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Whether `val` has the tag, if there is one
+    fn has_tag(&self, val: T) -> bool {
+        self.tag
+            .is_none_or(|(tag_bits, tag)| val.to_bits() >> (T::BITS - tag_bits) == tag)
+    }
+
+    pub(crate) fn set_override(&mut self, override_val: T)
+    where
+        T: Ord,
+    {
+        match &self.parity {
+            Some(parity) => {
+                // an invalid (but allowed) override has no index, so keep counting from the
+                // previous one
+                if let Some(index) = parity.decode(override_val.to_bits(), T::BITS) {
+                    self.prev_val = Some(T::from_bits(index));
+                }
+            }
+            None => self.prev_val = Some(override_val),
+        }
+    }
+
+    /// Marks `val` as taken, so that no value within `min_distance` of it is yielded
+    pub(crate) fn reserve(&mut self, val: T) {
+        self.codebook.push(val);
+    }
+
+    fn encode(&self, cursor: T) -> Option<T> {
+        match &self.parity {
+            Some(parity) => parity.encode(cursor.to_bits(), T::BITS).map(T::from_bits),
+            None => Some(cursor),
+        }
+    }
+
+    /// Like [`Iterator::next`], but also skips every value that is fewer than `distance` bits away
+    /// from any `(other, distance)` in `constraints`
+    ///
+    /// Gives up once too many values have been checked, see [`Self::gave_up`].
+    pub(crate) fn next_constrained(&mut self, constraints: &[(T, u32)]) -> Option<T> {
+        let mut budget = self.budget;
+        let next = self.search(constraints, &mut budget);
+        self.budget = budget;
+        next
+    }
+
+    /// Whether [`Self::next_constrained`] stopped because the search took too long, rather than
+    /// because no values are left
+    pub(crate) const fn gave_up(&self) -> bool {
+        self.budget == 0
+    }
+
+    /// The stuck value that [`Self::next_constrained`] stopped at, if the parity gives each index
+    /// a fixed codeword
+    pub(crate) const fn stuck(&self) -> Option<T> {
+        self.stuck
+    }
+
+    /// Like [`Self::next_constrained`], but each value that is checked takes from `budget`, and
+    /// once it runs out, `budget` is left at zero and `None` is returned
+    fn search(&mut self, constraints: &[(T, u32)], budget: &mut u128) -> Option<T> {
+        let mut cursor = match (self.prev_val, self.tag) {
+            (Some(cursor), _) => cursor.checked_increment()?,
+            (None, Some((tag_bits, tag))) => T::from_bits(tag << (T::BITS - tag_bits)),
+            (None, None) => T::zero(),
+        };
+        let val = loop {
+            if let Some(parity) = &self.parity {
+                cursor = cursor.seek(parity)?;
+            }
+            let val = self.encode(cursor)?;
+            // the values only go up, so once the tag changes, none are left
+            if !self.has_tag(val) {
+                return None;
+            }
+            if self.exclude_stuck
+                && val.is_stuck()
+                && self.parity.as_ref().is_some_and(fixes_indices)
+            {
+                self.stuck = Some(val);
+                return None;
+            }
+            let checks = if self.min_distance.is_some() {
+                self.codebook.len() + constraints.len() + 1
+            } else {
+                constraints.len() + 1
+            };
+            let Some(left) = budget.checked_sub(checks as u128) else {
+                *budget = 0;
+                return None;
+            };
+            *budget = left;
+            if self.accepts(val)
+                && constraints
+                    .iter()
+                    .all(|&(other, distance)| other.distance(val) >= distance)
+            {
+                break val;
+            }
+            cursor = cursor.checked_increment()?;
+        };
+
+        self.prev_val = Some(cursor);
+        self.codebook.push(val);
+
+        Some(val)
+    }
+
+    /// How many more values this yields
+    ///
+    /// With a minimum distance, every value depends on the ones before it, so they are stepped
+    /// through until the search takes too long. Otherwise the values are counted in closed form,
+    /// either up to the first reserved one, which would be yielded again, or without any of the
+    /// reserved ones if `skip_reserved` is set.
+    pub(crate) fn remaining(self, skip_reserved: bool) -> Remaining {
+        if self.min_distance.is_some() {
+            return self.step_through();
+        }
+
+        let ones = u128::MAX >> (u128::BITS - T::BITS);
+        let (start, mut last) = match self.tag {
+            Some((tag_bits, tag)) => {
+                let shift = T::BITS - tag_bits;
+                (tag << shift, (tag << shift) | (ones >> tag_bits))
+            }
+            None => (0, ones),
+        };
+        let start = match self.prev_val {
+            Some(prev) if prev.to_bits() >= last => return Remaining::Exact(0),
+            Some(prev) => prev.to_bits() + 1,
+            None => start,
+        };
+        let check = self
+            .parity
+            .as_ref()
+            .filter(|parity| counts_values(parity))
+            .map(|parity| ParityCheck::new(Some(parity), T::BITS));
+        // a systematic code has a codeword for every index up to its capacity
+        if check.is_none() && self.encode(T::from_bits(start)).is_none() {
+            return Remaining::Exact(0);
+        }
+        if check.is_none() {
+            let (mut low, mut high) = (start, last);
+            while low < high {
+                let mid = low + (high - low).div_ceil(2);
+                if self.encode(T::from_bits(mid)).is_some() {
+                    low = mid;
+                } else {
+                    high = mid - 1;
+                }
+            }
+            last = low;
+        }
+
+        // the cursor that yields `val`, if any does
+        let cursor_of = |val: T| {
+            let cursor = match &self.parity {
+                Some(parity) => parity.decode(val.to_bits(), T::BITS)?,
+                None => val.to_bits(),
+            };
+            let yields = self.encode(T::from_bits(cursor)).map(T::to_bits) == Some(val.to_bits());
+            yields.then_some(cursor)
+        };
+        let reserved = self
+            .codebook
+            .iter()
+            .filter(|&&val| self.accepts(val))
+            .filter_map(|&val| cursor_of(val))
+            .filter(|cursor| (start..=last).contains(cursor))
+            .sorted_unstable()
+            .dedup()
+            .collect_vec();
+        let skipped = if skip_reserved {
+            reserved.len() as u128
+        } else {
+            // a further value would collide with the first reserved one
+            if let Some(&first) = reserved.first() {
+                if first == start {
+                    return Remaining::Exact(0);
+                }
+                last = first - 1;
+            }
+            0
+        };
+
+        // the values before `last`, and whether `last` itself is one, so that every value of a
+        // `u128` can be counted
+        let (before, at_last) = match &check {
+            Some(check) => {
+                let count_below = |end| check.count_below(end, T::BITS).unwrap_or_default();
+                let last_passes = check.passes(last).unwrap_or_default();
+                (
+                    count_below(last) - count_below(start),
+                    u128::from(last_passes),
+                )
+            }
+            None => (last - start, 1),
+        };
+        // the stuck values may be counted but not accepted
+        let stuck = stuck_values::<T>()
+            .filter(|&val| {
+                let counted = check
+                    .as_ref()
+                    .is_none_or(|check| check.passes(val.to_bits()).unwrap_or_default());
+                counted && !self.accepts(val)
+            })
+            .filter_map(cursor_of);
+        // and so may the first index of a systematic code, which does not always pass its own
+        // check, as with Verhoeff
+        let unchecked = self
+            .encode(T::from_bits(start))
+            .filter(|&val| check.is_none() && !self.accepts(val))
+            .map(|_| start);
+        let rejected = stuck
+            .chain(unchecked)
+            .filter(|cursor| (start..=last).contains(cursor))
+            .sorted_unstable()
+            .dedup()
+            .count() as u128;
+
+        let excluded = rejected + skipped;
+        Remaining::Exact(match excluded.checked_sub(at_last) {
+            Some(excluded) => before - excluded,
+            None => before.saturating_add(at_last),
+        })
+    }
+
+    /// Counts the remaining values by yielding each of them, until the search takes too long
+    fn step_through(mut self) -> Remaining {
+        let mut budget = COUNT_BUDGET;
+        let mut count = 0;
+        while self.search(&[], &mut budget).is_some() {
+            count += 1;
+        }
+        if budget == 0 {
+            Remaining::AtLeast(count)
+        } else {
+            Remaining::Exact(count)
+        }
+    }
+
+    fn accepts(&self, val: T) -> bool {
+        self.parity
+            .as_ref()
+            .is_none_or(|parity| val.has_parity(parity))
+            && !(self.exclude_stuck && val.is_stuck())
+            && self.min_distance.is_none_or(|min_distance| {
+                self.codebook
+                    .iter()
+                    .all(|&used| used.distance(val) >= min_distance)
+            })
+    }
+}
+
+/// Whether variant `i` always gets the codeword of index `i`, so no codeword can be skipped
+const fn fixes_indices(parity: &Parity) -> bool {
+    matches!(parity, Parity::Gray(_) | Parity::ParityBit(_))
+}
+
+/// Whether the values are counted through directly, rather than through an index that is encoded
+const fn counts_values(parity: &Parity) -> bool {
+    match parity {
+        Parity::Even
+        | Parity::Odd
+        | Parity::Weight(_)
+        | Parity::Correcting(Correcting::CheckMatrix(_)) => true,
+        Parity::Balanced(balanced) => !balanced.is_manchester(),
+        Parity::Berger
+        | Parity::Crc(_)
+        | Parity::Gray(_)
+        | Parity::Burst(_)
+        | Parity::Complement
+        | Parity::Chunked(_)
+        | Parity::ParityBit(_)
+        | Parity::Repeated(_)
+        | Parity::Correcting(_)
+        | Parity::CheckDigit(_) => false,
+    }
+}
+
+impl<T> Iterator for BitParityIter<T>
+where
+    T: IntegerParity,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_constrained(&[])
+    }
+}
+
+macro_rules! unsigned_int_par_impl {
+    ($($int:ty $(=> $target_bits:expr)?),* ) => {
+    $(impl IntegerParity for $int {
+            const BITS: u32 = <$int>::BITS;
+            $(const TARGET_BITS: &'static [u32] = &$target_bits;)?
+            fn zero() -> Self { 0 }
+            fn checked_increment(self) -> Option<Self> { self.checked_add(1 as _) }
+            fn has_parity(self, parity: &Parity) -> bool {
+                match parity {
+                    Parity::Even => self.count_ones().is_multiple_of(2),
+                    Parity::Odd => !self.count_ones().is_multiple_of(2),
+                    Parity::Weight(weight) => self.count_ones() == *weight,
+                    Parity::Correcting(Correcting::CheckMatrix(matrix)) => {
+                        matrix.syndrome(self.to_bits()) == 0
+                    }
+                    Parity::Berger
+                    | Parity::Crc(_)
+                    | Parity::Gray(_)
+                    | Parity::Balanced(_)
+                    | Parity::Burst(_)
+                    | Parity::Complement
+                    | Parity::Chunked(_)
+                    | Parity::ParityBit(_)
+                    | Parity::Repeated(_)
+                    | Parity::Correcting(_)
+                    | Parity::CheckDigit(_) => parity.decode(self.to_bits(), Self::BITS).is_some(),
+                }
+            }
+            fn seek(self, parity: &Parity) -> Option<Self> {
+                let mut val = self;
+                match parity {
+                    Parity::Even | Parity::Odd | Parity::Correcting(Correcting::CheckMatrix(_)) => {
+                        while !val.has_parity(parity) {
+                            val = val.checked_add(1)?;
+                        }
+                    }
+                    &Parity::Weight(weight) => {
+                        if weight > Self::BITS {
+                            return None;
+                        }
+                        // every value below the next carry out of the lowest run of ones has too
+                        // many bits set, so skip straight to it
+                        while val.count_ones() > weight {
+                            val = val.checked_add(1 << val.trailing_zeros())?;
+                        }
+                        // then set the lowest clear bits until there are enough
+                        while val.count_ones() < weight {
+                            val |= val + 1;
+                        }
+                    }
+                    // systematic codes count through indices, which are all valid
+                    Parity::Balanced(balanced) if !balanced.is_manchester() => {
+                        val = val.seek(&Parity::Weight(Self::BITS / 2))?;
+                    }
+                    Parity::Berger
+                    | Parity::Crc(_)
+                    | Parity::Gray(_)
+                    | Parity::Balanced(_)
+                    | Parity::Burst(_)
+                    | Parity::Complement
+                    | Parity::Chunked(_)
+                    | Parity::ParityBit(_)
+                    | Parity::Repeated(_)
+                    | Parity::Correcting(_)
+                    | Parity::CheckDigit(_) => {}
+                }
+                Some(val)
+            }
+            fn distance(self, other: Self) -> u32 { (self ^ other).count_ones() }
+            #[allow(clippy::cast_lossless, reason = "`usize` does not implement `Into<u128>`")]
+            fn to_bits(self) -> u128 { self as u128 }
+            fn from_bits(bits: u128) -> Self { bits as Self }
+        })*
+    };
+    }
+macro_rules! signed_int_par_impl {
+    ($($int:ty $(=> $target_bits:expr)?),* ) => {
+    $(impl IntegerParity for $int {
+            const BITS: u32 = <$int>::BITS;
+            $(const TARGET_BITS: &'static [u32] = &$target_bits;)?
+            fn zero() -> Self { 0 }
+            fn checked_increment(self) -> Option<Self> {
+                self.cast_unsigned().checked_add(1).map(|val| val.cast_signed())
+            }
+            fn has_parity(self, parity: &Parity) -> bool {
+                self.cast_unsigned().has_parity(parity)
+            }
+            fn seek(self, parity: &Parity) -> Option<Self> {
+                self.cast_unsigned().seek(parity).map(|val| val.cast_signed())
+            }
+            fn distance(self, other: Self) -> u32 { (self ^ other).count_ones() }
+            fn to_bits(self) -> u128 { self.cast_unsigned().to_bits() }
+            #[allow(clippy::cast_possible_wrap, reason = "only the bits matter")]
+            fn from_bits(bits: u128) -> Self { bits as Self }
+        })*
+    };
+}
+
+// the pointer widths that Rust supports
+unsigned_int_par_impl!(u8, u16, u32, u64, u128, usize => [16, 32, 64]);
+signed_int_par_impl!(i8, i16, i32, i64, i128, isize => [16, 32, 64]);
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+
+    use crate::{Correcting, Parity, check_digit::CheckDigit, check_matrix::CheckMatrix, crc::Crc};
+
+    use super::{BitParityIter, IntegerParity, Remaining};
+    use itertools::Itertools;
+
+    /// Checks the count of `remaining` against stepping through the values, after `taken` values,
+    /// with and without one of the later values reserved
+    fn assert_remaining<T>(make: impl Fn() -> BitParityIter<T>, taken: usize)
+    where
+        T: IntegerParity + PartialEq + std::fmt::Debug,
+    {
+        let mut iter = make();
+        iter.by_ref().take(taken).for_each(drop);
+        let all = iter.clone().count() as u128;
+        assert_eq!(iter.remaining(false), Remaining::Exact(all), "{taken}");
+
+        let Some(reserved) = make().nth(taken + 3) else {
+            return;
+        };
+        let mut iter = make();
+        iter.reserve(reserved);
+        iter.by_ref().take(taken).for_each(drop);
+        let before = iter.clone().take_while(|&val| val != reserved).count() as u128;
+        assert_eq!(iter.remaining(false), Remaining::Exact(before));
+
+        let mut iter = make();
+        iter.reserve(reserved);
+        iter.reserve(reserved);
+        assert_eq!(
+            iter.remaining(true),
+            Remaining::Exact(make().count() as u128 - 1)
+        );
+    }
+
+    #[test]
+    pub fn small_even_parity() {
+        let iter = BitParityIter::<usize>::new(Some(Parity::Even), None);
+        let v = iter.take(4).collect_vec();
+
+        assert_eq!(v, [0x00, 0x3, 0x05, 0x06]);
+    }
+
+    #[test]
+    pub fn small_odd_parity() {
+        let iter = BitParityIter::<usize>::new(Some(Parity::Odd), None);
+        let v = iter.take(4).collect_vec();
+
+        assert_eq!(v, [0x01, 0x2, 0x04, 0x07]);
+    }
+
+    #[test]
+    pub fn overflowing_u8_even_parity() {
+        let iter = BitParityIter::<u8>::new(Some(Parity::Even), None);
+        let v = iter.skip(124).collect_vec();
+
+        assert_eq!(v, [0xf9, 0xfa, 0xfc, 0xff]);
+    }
+
+    #[test]
+    pub fn overflowing_u8_odd_parity() {
+        let iter = BitParityIter::<u8>::new(Some(Parity::Odd), None);
+        let v = iter.skip(124).collect_vec();
+
+        assert_eq!(v, [0xf8, 0xfb, 0xfd, 0xfe]);
+    }
+
+    #[test]
+    pub fn even_parity_excluding_stuck() {
+        let iter = BitParityIter::<u8>::new(Some(Parity::Even), None).excluding_stuck(true);
+        let v = iter.collect_vec();
+
+        assert_eq!(v[..3], [0x03, 0x05, 0x06]);
+        assert_eq!(v[v.len() - 3..], [0xf9, 0xfa, 0xfc]);
+        assert_eq!(v.len(), 126);
+    }
+
+    #[test]
+    pub fn i32_excluding_stuck() {
+        let mut iter = BitParityIter::<i32>::new(Some(Parity::Weight(32)), None);
+        assert_eq!(iter.next(), Some(-1));
+
+        let mut iter =
+            BitParityIter::<i32>::new(Some(Parity::Weight(32)), None).excluding_stuck(true);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    pub fn usize_excluding_stuck_at_every_pointer_width() {
+        // all ones on a 16-bit target
+        let mut iter =
+            BitParityIter::<usize>::new(Some(Parity::Weight(16)), None).excluding_stuck(true);
+        assert_eq!(iter.next(), Some(0x1_7fff));
+
+        let mut iter =
+            BitParityIter::<u32>::new(Some(Parity::Weight(16)), None).excluding_stuck(true);
+        assert_eq!(iter.next(), Some(0xffff));
+    }
+
+    // these `i8`s should be the same as `u8`, since we only care about the bits
+    #[test]
+    pub fn overflowing_i8_even_parity() {
+        let iter = BitParityIter::<i8>::new(Some(Parity::Even), None);
+        let v = iter.skip(124).collect_vec();
+
+        assert_eq!(v, [0xf9_u8, 0xfa_u8, 0xfc_u8, 0xff_u8].map(u8::cast_signed));
+    }
+
+    #[test]
+    pub fn overflowing_i8_odd_parity() {
+        let iter = BitParityIter::<i8>::new(Some(Parity::Odd), None);
+        let v = iter.skip(124).collect_vec();
+
+        assert_eq!(v, [0xf8_u8, 0xfb_u8, 0xfd_u8, 0xfe_u8].map(u8::cast_signed));
+    }
+
+    #[test]
+    pub fn small_weight() {
+        let iter = BitParityIter::<u8>::new(Some(Parity::Weight(2)), None);
+        let v = iter.take(5).collect_vec();
+
+        assert_eq!(v, [0x03, 0x05, 0x06, 0x09, 0x0a]);
+    }
+
+    #[test]
+    pub fn weight_u8_capacity() {
+        // 8 choose 3
+        assert_eq!(
+            BitParityIter::<u8>::new(Some(Parity::Weight(3)), None).count(),
+            56
+        );
+        assert_eq!(
+            BitParityIter::<u8>::new(Some(Parity::Weight(8)), None).collect_vec(),
+            [0xff]
+        );
+        assert_eq!(
+            BitParityIter::<u8>::new(Some(Parity::Weight(9)), None).count(),
+            0
+        );
+    }
+
+    #[test]
+    pub fn wide_weight_i64() {
+        let iter = BitParityIter::<i64>::new(Some(Parity::Weight(32)), None);
+        let v = iter.take(2).collect_vec();
+
+        assert_eq!(v, [0xffff_ffff, 0x1_7fff_ffff]);
+    }
+
+    #[test]
+    pub fn small_berger() {
+        let iter = BitParityIter::<u8>::new(Some(Parity::Berger), None);
+        let v = iter.take(4).collect_vec();
+
+        assert_eq!(v, [0xa0, 0x81, 0x82, 0x63]);
+    }
+
+    #[test]
+    pub fn berger_override() {
+        let mut iter = BitParityIter::<u8>::new(Some(Parity::Berger), None);
+        iter.set_override(0x6c);
+        let v = iter.take(2).collect_vec();
+
+        assert_eq!(v, [0x4d, 0x4e]);
+    }
+
+    #[test]
+    pub fn berger_capacity() {
+        assert_eq!(
+            BitParityIter::<u8>::new(Some(Parity::Berger), None).count(),
+            32
+        );
+        assert_eq!(
+            BitParityIter::<i16>::new(Some(Parity::Berger), None).count(),
+            4096
+        );
+    }
+
+    #[test]
+    pub fn min_distance_three() {
+        let iter = BitParityIter::<u8>::new(None, Some(3));
+        let v = iter.take(4).collect_vec();
+
+        assert_eq!(v, [0x00, 0x07, 0x19, 0x1e]);
+    }
+
+    #[test]
+    pub fn min_distance_four_even_parity() {
+        let iter = BitParityIter::<u8>::new(Some(Parity::Even), Some(4));
+        let v = iter.take(4).collect_vec();
+
+        assert_eq!(v, [0x00, 0x0f, 0x33, 0x3c]);
+    }
+
+    #[test]
+    pub fn min_distance_u8_capacity() {
+        // the binary lexicodes are linear, so these are all powers of 2
+        assert_eq!(BitParityIter::<u8>::new(None, Some(3)).count(), 16);
+        assert_eq!(BitParityIter::<u8>::new(None, Some(4)).count(), 16);
+        assert_eq!(BitParityIter::<u8>::new(None, Some(5)).count(), 4);
+    }
+
+    #[test]
+    pub fn constrained_skips_close_values() {
+        let mut iter = BitParityIter::<u8>::new(Some(Parity::Even), None);
+        let safe = iter.next().unwrap();
+        let armed = iter.next_constrained(&[(safe, 6)]).unwrap();
+
+        assert_eq!((safe, armed), (0x00, 0x3f));
+        // the cursor carries on from the constrained value
+        assert_eq!(iter.next(), Some(0x41));
+    }
+
+    #[test]
+    pub fn tagged_stays_in_range() {
+        let v = BitParityIter::<u8>::new(Some(Parity::Odd), None)
+            .tagged(Some((5, 0b10110)))
+            .collect_vec();
+
+        assert_eq!(v, [0xb0, 0xb3, 0xb5, 0xb6]);
+    }
+
+    #[test]
+    pub fn min_distance_skips_reserved() {
+        let mut iter = BitParityIter::<u8>::new(None, Some(3));
+        iter.reserve(0x07);
+        let v = iter.take(3).collect_vec();
+
+        assert_eq!(v, [0x00, 0x19, 0x1e]);
+    }
+
+    #[test]
+    pub fn remaining_matches_count() {
+        let parities = [
+            Parity::Even,
+            Parity::Odd,
+            Parity::Weight(3),
+            Parity::Berger,
+            Parity::Correcting(Correcting::Secded),
+            Parity::Crc(Crc::from_string("crc4-itu").unwrap()),
+            Parity::Burst(3),
+            Parity::CheckDigit(CheckDigit::from_string("verhoeff").unwrap()),
+            Parity::Correcting(Correcting::CheckMatrix(
+                CheckMatrix::from_expr(&syn::parse_quote!([0x55, 0x66])).unwrap(),
+            )),
+        ];
+        for parity in parities {
+            for taken in [0, 1, 6] {
+                for exclude_stuck in [false, true] {
+                    let make = || {
+                        BitParityIter::<u8>::new(Some(parity.clone()), None)
+                            .excluding_stuck(exclude_stuck)
+                    };
+                    assert_remaining(make, taken);
+                    assert_remaining(
+                        || {
+                            BitParityIter::<i16>::new(Some(parity.clone()), None)
+                                .excluding_stuck(exclude_stuck)
+                        },
+                        taken,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn remaining_tagged() {
+        for parity in [Parity::Even, Parity::Weight(2)] {
+            assert_remaining(
+                || BitParityIter::<u8>::new(Some(parity.clone()), None).tagged(Some((3, 0b101))),
+                2,
+            );
+            assert_remaining(
+                || BitParityIter::<i8>::new(Some(parity.clone()), None).tagged(Some((1, 1))),
+                0,
+            );
+        }
+    }
+
+    #[test]
+    pub fn remaining_wide() {
+        let mut iter = BitParityIter::<u128>::new(Some(Parity::Even), None).excluding_stuck(true);
+        iter.by_ref().take(3).for_each(drop);
+        // every even value but the three taken and the stuck ones
+        assert_eq!(iter.remaining(false), Remaining::Exact((1 << 127) - 5));
+
+        let weight = BitParityIter::<u64>::new(Some(Parity::Weight(32)), None);
+        assert_eq!(
+            weight.remaining(false),
+            Remaining::Exact(1_832_624_140_942_590_534)
+        );
+    }
+
+    #[test]
+    pub fn remaining_min_distance() {
+        let mut iter = BitParityIter::<u8>::new(None, Some(3));
+        iter.by_ref().take(4).for_each(drop);
+        assert_eq!(iter.remaining(false), Remaining::Exact(12));
+
+        // far too many to step through
+        let wide = BitParityIter::<u64>::new(None, Some(3));
+        assert!(matches!(wide.remaining(false), Remaining::AtLeast(_)));
+    }
+}
@@ -0,0 +1,109 @@
+//! Burst error detection with interleaved parity
+//!
+//! The variant index is in the high bits, and the low `b` bits are the XOR of the index in `b` bit
+//! chunks. Every bit of the code is then covered by the parity check of its position modulo `b`. A
+//! burst of up to `b` adjacent bits flips at most one bit covered by each check, so at least one
+//! check fails.
+
+/// the number of index bits in a `bits` bit code
+const fn data_bits(burst: u32, bits: u32) -> u32 {
+    bits.saturating_sub(burst)
+}
+
+/// the number of codewords that fit in `bits` bits
+pub const fn capacity(burst: u32, bits: u32) -> u128 {
+    if burst >= bits {
+        0
+    } else {
+        1 << data_bits(burst, bits)
+    }
+}
+
+/// the XOR of `index` in `burst` bit chunks
+fn checksum(index: u128, burst: u32) -> u128 {
+    let mask = u128::MAX >> (u128::BITS - burst);
+    let mut checksum = 0;
+    let mut rest = index;
+    while rest != 0 {
+        checksum ^= rest & mask;
+        rest = rest.checked_shr(burst).unwrap_or(0);
+    }
+    checksum
+}
+
+pub fn encode(index: u128, burst: u32, bits: u32) -> Option<u128> {
+    if burst >= bits || index.checked_shr(data_bits(burst, bits)).unwrap_or(0) != 0 {
+        return None;
+    }
+
+    Some(index << burst | checksum(index, burst))
+}
+
+/// the index of `code`, or `None` if an interleaved parity check fails
+pub fn decode(code: u128, burst: u32, bits: u32) -> Option<u128> {
+    if burst >= bits {
+        return None;
+    }
+    let index = code >> burst;
+    (encode(index, burst, bits) == Some(code)).then_some(index)
+}
+
+/// The length of the shortest burst that turns `a` into `b`
+///
+/// This is the span from the lowest to the highest differing bit, or 0 if they are equal.
+pub const fn length(a: u128, b: u128) -> u32 {
+    let diff = a ^ b;
+    if diff == 0 {
+        0
+    } else {
+        u128::BITS - diff.leading_zeros() - diff.trailing_zeros()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{capacity, decode, encode, length};
+
+    #[test]
+    pub fn small_burst_3() {
+        let codes = (0..4).map(|index| encode(index, 3, 8).unwrap());
+
+        // the index, followed by itself in the low bits
+        assert!(codes.eq([0x00, 0x09, 0x12, 0x1b]));
+        // `0b001 ^ 0b001` cancels out
+        assert_eq!(encode(0b1001, 3, 8), Some(0b1001 << 3));
+        assert_eq!(capacity(3, 8), 32);
+        assert_eq!(encode(32, 3, 8), None);
+    }
+
+    #[test]
+    pub fn every_short_burst_detected() {
+        for burst in 1..6 {
+            for index in 0..capacity(burst, 16) {
+                let code = encode(index, burst, 16).unwrap();
+                for start in 0..16 {
+                    for pattern in 1..1_u128 << burst {
+                        let corrupted = code ^ (pattern << start & 0xffff);
+                        if corrupted != code {
+                            assert_eq!(decode(corrupted, burst, 16), None);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn burst_length() {
+        assert_eq!(length(0b1000_0001, 0), 8);
+        assert_eq!(length(0b0110_0000, 0b0100_0000), 1);
+        assert_eq!(length(7, 7), 0);
+    }
+
+    #[test]
+    pub fn too_wide() {
+        assert_eq!(capacity(8, 8), 0);
+        assert_eq!(encode(0, 8, 8), None);
+        assert_eq!(decode(0, 200, 8), None);
+    }
+}
@@ -0,0 +1,284 @@
+//! Decimal check digits, for discriminants that are typed in by hand
+//!
+//! The discriminant is the variant index in decimal, followed by one check digit. Both the Damm
+//! and the Verhoeff algorithm detect every single wrong digit and every swap of two adjacent
+//! digits. A value is only valid if its decimal form, without leading zeros, passes the check, so
+//! in a signed repr only the non-negative values are used.
+
+use std::fmt::Display;
+
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// The Damm quasigroup of order 10, which has a zero diagonal
+const DAMM: [[u8; 10]; 10] = [
+    [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+    [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+    [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+    [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+    [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+    [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+    [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+    [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+    [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+    [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+];
+
+/// Multiplication in the dihedral group `D5`
+const VERHOEFF_MUL: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// The permutation applied to a digit, by its position from the right modulo 8
+const VERHOEFF_PERM: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 8, 7, 6, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+/// The inverse of each element of `D5`
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "snake_case")]
+enum Algorithm {
+    Damm,
+    Verhoeff,
+}
+
+/// A decimal check digit after the variant index
+#[derive(Copy, Clone, Debug)]
+pub struct CheckDigit {
+    algorithm: Algorithm,
+    /// whether the repr is signed, so that its top bit is left clear
+    signed: bool,
+}
+
+/// the decimal digits of `val`, most significant first
+fn digits(val: u128) -> Vec<u8> {
+    val.to_string().bytes().map(|digit| digit - b'0').collect()
+}
+
+impl CheckDigit {
+    /// Leaves the top bit clear if the repr is signed
+    pub const fn with_signed(self, signed: bool) -> Self {
+        Self { signed, ..self }
+    }
+
+    /// The name of the algorithm, as it is written in prose
+    pub const fn name(self) -> &'static str {
+        match self.algorithm {
+            Algorithm::Damm => "Damm",
+            Algorithm::Verhoeff => "Verhoeff",
+        }
+    }
+
+    /// the largest value that is non-negative in a `bits` bit repr
+    pub const fn max(self, bits: u32) -> u128 {
+        let bits = if self.signed { bits - 1 } else { bits };
+        u128::MAX >> (u128::BITS - bits)
+    }
+
+    /// the check digit to append to `index`
+    fn digit(self, index: u128) -> u8 {
+        let digits = digits(index);
+        match self.algorithm {
+            Algorithm::Damm => digits
+                .iter()
+                .fold(0, |interim, &digit| DAMM[interim as usize][digit as usize]),
+            Algorithm::Verhoeff => {
+                let check = digits
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .fold(0, |check, (pos, &digit)| {
+                        VERHOEFF_MUL[check as usize]
+                            [VERHOEFF_PERM[(pos + 1) % 8][digit as usize] as usize]
+                    });
+                VERHOEFF_INV[check as usize]
+            }
+        }
+    }
+
+    /// Whether the decimal form of `val` ends in a valid check digit
+    pub fn is_valid(self, val: u128) -> bool {
+        let digits = digits(val);
+        let check = match self.algorithm {
+            Algorithm::Damm => digits
+                .iter()
+                .fold(0, |interim, &digit| DAMM[interim as usize][digit as usize]),
+            Algorithm::Verhoeff => {
+                digits
+                    .iter()
+                    .rev()
+                    .enumerate()
+                    .fold(0, |check, (pos, &digit)| {
+                        VERHOEFF_MUL[check as usize]
+                            [VERHOEFF_PERM[pos % 8][digit as usize] as usize]
+                    })
+            }
+        };
+        check == 0
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        let val = index
+            .checked_mul(10)?
+            .checked_add(self.digit(index).into())?;
+        (val <= self.max(bits)).then_some(val)
+    }
+
+    /// the index of `val`, or `None` if it does not end in a valid check digit
+    pub fn decode(self, val: u128, bits: u32) -> Option<u128> {
+        (val <= self.max(bits) && self.is_valid(val)).then_some(val / 10)
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub fn capacity(self, bits: u32) -> u128 {
+        // every index below the last one fits. With Verhoeff, index 0 is not valid as a lone check
+        // digit, but `0` itself is.
+        let last = self.max(bits) / 10;
+        last + u128::from(self.encode(last, bits).is_some())
+    }
+
+    /// An expression that is `true` if the decimal digits in `bytes` end in a valid check digit
+    pub fn validate(self) -> TokenStream {
+        let rows = |table: &[[u8; 10]]| {
+            table
+                .iter()
+                .map(|row| quote!([#(#row),*]))
+                .collect::<Vec<_>>()
+        };
+
+        match self.algorithm {
+            Algorithm::Damm => {
+                let damm = rows(&DAMM);
+                quote! {{
+                    const DAMM: [[u8; 10]; 10] = [#(#damm),*];
+                    let mut interim = 0;
+                    let mut idx = 0;
+                    while idx < bytes.len() {
+                        interim = DAMM[interim as usize][(bytes[idx] - b'0') as usize];
+                        idx += 1;
+                    }
+                    interim == 0
+                }}
+            }
+            Algorithm::Verhoeff => {
+                let mul = rows(&VERHOEFF_MUL);
+                let perm = rows(&VERHOEFF_PERM);
+                quote! {{
+                    const MUL: [[u8; 10]; 10] = [#(#mul),*];
+                    const PERM: [[u8; 10]; 8] = [#(#perm),*];
+                    let mut check = 0;
+                    let mut pos = 0;
+                    while pos < bytes.len() {
+                        let digit = bytes[bytes.len() - 1 - pos] - b'0';
+                        check = MUL[check as usize][PERM[pos % 8][digit as usize] as usize];
+                        pos += 1;
+                    }
+                    check == 0
+                }}
+            }
+        }
+    }
+}
+
+impl FromMeta for CheckDigit {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(Self {
+            algorithm: Algorithm::from_string(value)?,
+            signed: false,
+        })
+    }
+}
+
+impl Display for CheckDigit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let algorithm = match self.algorithm {
+            Algorithm::Damm => "damm",
+            Algorithm::Verhoeff => "verhoeff",
+        };
+        write!(f, "check_digit = \"{algorithm}\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, CheckDigit};
+
+    const DAMM: CheckDigit = CheckDigit {
+        algorithm: Algorithm::Damm,
+        signed: false,
+    };
+    const VERHOEFF: CheckDigit = CheckDigit {
+        algorithm: Algorithm::Verhoeff,
+        signed: false,
+    };
+
+    #[test]
+    pub fn known_check_digits() {
+        assert_eq!(DAMM.digit(572), 4);
+        assert!(DAMM.is_valid(5724));
+        assert_eq!(VERHOEFF.digit(236), 3);
+        assert!(VERHOEFF.is_valid(2363));
+        assert_eq!(VERHOEFF.digit(12345), 1);
+    }
+
+    #[test]
+    pub fn detects_typos() {
+        for check_digit in [DAMM, VERHOEFF] {
+            for index in 1..2000 {
+                let val = check_digit.encode(index, 32).unwrap();
+                let digits = val.to_string().into_bytes();
+                for pos in 0..digits.len() {
+                    for typo in b'0'..=b'9' {
+                        let mut typed = digits.clone();
+                        typed[pos] = typo;
+                        if typed != digits && typed[0] != b'0' {
+                            let typed = String::from_utf8(typed).unwrap().parse().unwrap();
+                            assert!(!check_digit.is_valid(typed), "{val} {typed}");
+                        }
+                    }
+                    if pos + 1 < digits.len()
+                        && digits[pos] != digits[pos + 1]
+                        && (pos > 0 || digits[1] != b'0')
+                    {
+                        let mut swapped = digits.clone();
+                        swapped.swap(pos, pos + 1);
+                        let swapped = String::from_utf8(swapped).unwrap().parse().unwrap();
+                        assert!(!check_digit.is_valid(swapped), "{val} {swapped}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn capacity_per_repr() {
+        for check_digit in [DAMM, VERHOEFF] {
+            for (bits, signed) in [(8, false), (8, true), (16, false), (16, true)] {
+                let check_digit = check_digit.with_signed(signed);
+                let valid = (0..1 << bits)
+                    .filter(|&val| check_digit.decode(val, bits).is_some())
+                    .count();
+                assert_eq!(check_digit.capacity(bits), valid as u128);
+            }
+        }
+    }
+}
@@ -0,0 +1,305 @@
+//! Linear block codes from a parity-check matrix
+//!
+//! Each row of the matrix is a mask of the bits that one parity equation covers. A value is a
+//! codeword when every equation has even parity, i.e. its syndrome is zero. Plain `even` parity is
+//! the matrix with a single row of all ones.
+
+use std::{collections::HashMap, fmt::Display, rc::Rc};
+
+use darling::FromMeta;
+use itertools::Itertools;
+
+/// the number of codewords that are weighed to find the minimum distance, before the columns are
+/// searched instead
+const SEARCH_BUDGET: u128 = 1 << 24;
+
+/// the number of sets of columns that are kept while searching for the minimum distance, before
+/// settling for a lower bound
+const COLUMN_BUDGET: u128 = 1 << 20;
+
+/// The minimum distance of a code, as far as it could be searched
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MinDistance {
+    Exact(u32),
+    /// the search took too long, but found no codewords closer than this
+    AtLeast(u32),
+}
+
+/// A parity-check matrix, one row per parity equation
+#[derive(Clone, Debug)]
+pub struct CheckMatrix {
+    /// the rows, as masks over the discriminant
+    rows: Rc<[u128]>,
+}
+
+impl CheckMatrix {
+    pub fn rows(&self) -> &[u128] {
+        &self.rows
+    }
+
+    /// the bits of the syndrome of `code`, where bit `i` is set if row `i` has odd parity
+    pub fn syndrome(&self, code: u128) -> u128 {
+        self.rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| (*row & code).count_ones() % 2 == 1)
+            .fold(0, |syndrome, (idx, _)| syndrome | 1 << idx)
+    }
+
+    /// the index of the first row that `code` fails, if any
+    pub fn failing_row(&self, code: u128) -> Option<usize> {
+        (self.syndrome(code) != 0).then(|| self.syndrome(code).trailing_zeros() as usize)
+    }
+
+    /// A basis of every codeword in `bits` bits
+    fn basis(&self, bits: u32) -> Vec<u128> {
+        null_space(&self.rows, bits)
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub fn capacity(&self, bits: u32) -> u128 {
+        1 << self.basis(bits).len()
+    }
+
+    pub fn decode(&self, code: u128) -> Option<u128> {
+        (self.syndrome(code) == 0).then_some(code)
+    }
+
+    /// The smallest number of bits that any two codewords in `bits` bits differ in
+    ///
+    /// The code is linear, so this is the smallest weight of a non-zero codeword. Either every
+    /// codeword is weighed, or the columns of the matrix are searched for a set that sums to zero,
+    /// whichever is cheaper. A set of `w` columns sums to zero when one half of it has the same
+    /// syndrome as the other, so only sets of up to `w / 2` rounded up are stepped through. If that
+    /// takes too long, the weights that were ruled out give a lower bound.
+    pub fn min_distance(&self, bits: u32) -> MinDistance {
+        let basis = self.basis(bits);
+        if basis.is_empty() {
+            return MinDistance::Exact(bits);
+        }
+        if basis.len() <= SEARCH_BUDGET.ilog2() as usize {
+            // step through every codeword in Gray code order, one basis vector at a time
+            let mut code = 0;
+            let mut min = bits;
+            for step in 1_u128..1 << basis.len() {
+                code ^= basis[step.trailing_zeros() as usize];
+                min = min.min(code.count_ones());
+            }
+            return MinDistance::Exact(min);
+        }
+
+        let columns = (0..bits)
+            .map(|col| self.syndrome(1 << col))
+            .collect::<Vec<_>>();
+        // the syndrome of one set of `half - 1` columns each, as a mask of those columns
+        let mut smaller = HashMap::from([(0, 0)]);
+        let mut steps = 0;
+        for half in 1..=bits {
+            // every codeword of up to `2 * half` bits has a half of `half` columns, with the same
+            // syndrome as a smaller or equal half, and every lighter one was already ruled out
+            let mut halves = HashMap::<u128, u128>::new();
+            let mut min = None::<u32>;
+            for set in (0..bits).combinations(half as usize) {
+                steps += 1;
+                if steps > COLUMN_BUDGET {
+                    return MinDistance::AtLeast(2 * half - 1);
+                }
+                let mask = set.iter().fold(0, |mask, &col| mask | 1 << col);
+                let syndrome = set
+                    .iter()
+                    .fold(0, |syndrome, &col| syndrome ^ columns[col as usize]);
+                for other in [smaller.get(&syndrome), halves.get(&syndrome)]
+                    .into_iter()
+                    .flatten()
+                {
+                    let weight = (mask ^ other).count_ones();
+                    min = Some(min.map_or(weight, |min| min.min(weight)));
+                }
+                halves.entry(syndrome).or_insert(mask);
+            }
+            if let Some(min) = min {
+                return MinDistance::Exact(min);
+            }
+            smaller = halves;
+        }
+        MinDistance::Exact(bits)
+    }
+}
+
+/// A basis of every value in `bits` bits that has even parity under each of `rows`, found by row
+/// reducing them
+///
+/// This is the null space of `rows`, so for the generators of a linear code, it is a parity-check
+/// matrix of that code.
+pub fn null_space(rows: &[u128], bits: u32) -> Vec<u128> {
+    let mut rows = rows.to_vec();
+    let mut pivots = Vec::new();
+    for col in 0..bits {
+        let Some(found) = (pivots.len()..rows.len()).find(|&row| rows[row] >> col & 1 == 1) else {
+            continue;
+        };
+        rows.swap(pivots.len(), found);
+        let pivot = rows[pivots.len()];
+        for (idx, row) in rows.iter_mut().enumerate() {
+            if idx != pivots.len() && *row >> col & 1 == 1 {
+                *row ^= pivot;
+            }
+        }
+        pivots.push(col);
+    }
+
+    // every column without a pivot is free, and sets the pivots of the rows that cover it
+    (0..bits)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            pivots
+                .iter()
+                .zip(&rows)
+                .filter(|(_, row)| *row >> free & 1 == 1)
+                .fold(1 << free, |code, (&pivot, _)| code | 1 << pivot)
+        })
+        .collect()
+}
+
+/// `n` choose `k`, or `None` if it does not fit in a `u128`
+pub fn binomial(n: u32, k: u32) -> Option<u128> {
+    (1..=u128::from(k)).try_fold(1_u128, |acc, idx| {
+        Some(acc.checked_mul(u128::from(n) + 1 - idx)? / idx)
+    })
+}
+
+impl FromMeta for CheckMatrix {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let syn::Expr::Array(array) = expr else {
+            return Err(darling::Error::unexpected_expr_type(expr));
+        };
+        let rows = array
+            .elems
+            .iter()
+            .map(|row| match row {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit),
+                    ..
+                }) => lit.base10_parse::<u128>().map_err(darling::Error::from),
+                _ => Err(darling::Error::custom("expected an integer literal").with_span(row)),
+            })
+            .collect::<darling::Result<Vec<_>>>()?;
+        if rows.is_empty() {
+            return Err(darling::Error::custom(
+                "`check_matrix` needs at least one row",
+            ));
+        }
+        if rows.len() > u128::BITS as usize {
+            return Err(darling::Error::custom(format!(
+                "`check_matrix` has more than {} rows",
+                u128::BITS
+            )));
+        }
+
+        Ok(Self { rows: rows.into() })
+    }
+}
+
+impl Display for CheckMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| format!("{row:#x}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "check_matrix = [{rows}]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+
+    use super::{CheckMatrix, MinDistance, null_space};
+    use crate::bch::Bch;
+
+    /// the (7, 4) Hamming code, with the overall parity in bit 7
+    const EXTENDED_HAMMING: &[u128] = &[0x55, 0x66, 0x78, 0xff];
+
+    #[test]
+    pub fn hamming_code() {
+        let matrix = CheckMatrix {
+            rows: EXTENDED_HAMMING.into(),
+        };
+
+        assert_eq!(matrix.capacity(8), 16);
+        assert_eq!(matrix.min_distance(8), MinDistance::Exact(4));
+        assert_eq!(matrix.decode(0x00), Some(0x00));
+        assert_eq!(matrix.decode(0xff), Some(0xff));
+        assert_eq!(matrix.failing_row(0x01), Some(0));
+        assert_eq!(matrix.failing_row(0x80), Some(3));
+    }
+
+    #[test]
+    pub fn basis_spans_code() {
+        let matrix = CheckMatrix {
+            rows: EXTENDED_HAMMING.into(),
+        };
+        let basis = matrix.basis(8);
+
+        assert_eq!(basis.len(), 4);
+        for code in basis {
+            assert_eq!(matrix.syndrome(code), 0, "{code:#x}");
+        }
+    }
+
+    #[test]
+    pub fn even_parity() {
+        let even = CheckMatrix {
+            rows: [u128::MAX].into(),
+        };
+
+        assert_eq!(even.capacity(16), 1 << 15);
+        // too many codewords to weigh, so columns are summed instead
+        assert_eq!(even.min_distance(64), MinDistance::Exact(2));
+    }
+
+    #[test]
+    pub fn dependent_rows() {
+        let matrix = CheckMatrix {
+            rows: [0x0f, 0xf0, 0xff].into(),
+        };
+
+        assert_eq!(matrix.capacity(8), 1 << 6);
+        assert_eq!(matrix.min_distance(8), MinDistance::Exact(2));
+    }
+
+    /// the check matrix of the BCH code correcting `t` bits in `bits` bits, with the overall
+    /// parity in the top bit
+    fn extended_bch(t: u32, bits: u32) -> CheckMatrix {
+        let bch = Bch::from_meta(&syn::parse_str(&format!("bch(t = {t})")).unwrap()).unwrap();
+        let generators = (0..bch.capacity(bits).ilog2())
+            .map(|bit| {
+                let code = bch.encode(1 << bit, bits).unwrap();
+                code | u128::from(code.count_ones() % 2) << (bits - 1)
+            })
+            .collect::<Vec<_>>();
+
+        CheckMatrix {
+            rows: null_space(&generators, bits).into(),
+        }
+    }
+
+    #[test]
+    pub fn extended_bch_u64() {
+        let matrix = extended_bch(2, 64);
+
+        assert_eq!(matrix.rows().len(), 13);
+        // too many codewords to weigh, so the halves of each set of columns are matched up
+        assert_eq!(matrix.min_distance(64), MinDistance::Exact(6));
+    }
+
+    #[test]
+    pub fn lower_bound() {
+        let matrix = extended_bch(3, 128);
+
+        // the sets of 4 columns are too many to search, but none of 6 or fewer sum to zero
+        assert_eq!(matrix.min_distance(128), MinDistance::AtLeast(7));
+    }
+}
@@ -0,0 +1,191 @@
+//! Bit parity in every byte or nibble
+//!
+//! Whole-word parity misses one flipped bit in each of two bytes. With a finer granularity, every
+//! chunk has the requested parity on its own. The variant index is spread over the chunks, with
+//! the lowest bit of each chunk as its parity bit, which keeps the values in ascending order.
+
+use std::fmt::Display;
+
+use darling::FromMeta;
+
+/// The part of a discriminant that parity is enforced over
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum Granularity {
+    #[default]
+    Word,
+    Byte,
+    Nibble,
+}
+
+impl Granularity {
+    /// the number of bits in each chunk, or `None` for the whole word
+    const fn chunk_bits(self) -> Option<u32> {
+        match self {
+            Self::Word => None,
+            Self::Byte => Some(8),
+            Self::Nibble => Some(4),
+        }
+    }
+}
+
+impl Display for Granularity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word => write!(f, "word"),
+            Self::Byte => write!(f, "byte"),
+            Self::Nibble => write!(f, "nibble"),
+        }
+    }
+}
+
+/// Even or odd parity in every chunk of a discriminant
+#[derive(Copy, Clone, Debug)]
+pub struct Chunked {
+    odd: bool,
+    granularity: Granularity,
+    chunk_bits: u32,
+}
+
+impl Chunked {
+    /// `None` if `granularity` covers the whole word, which is plain `even` or `odd`
+    pub const fn new(odd: bool, granularity: Granularity) -> Option<Self> {
+        match granularity.chunk_bits() {
+            Some(chunk_bits) => Some(Self {
+                odd,
+                granularity,
+                chunk_bits,
+            }),
+            None => None,
+        }
+    }
+
+    pub const fn granularity(self) -> Granularity {
+        self.granularity
+    }
+
+    /// the parity of every chunk
+    pub const fn parity_name(self) -> &'static str {
+        if self.odd { "odd" } else { "even" }
+    }
+
+    /// the number of chunks in `bits` bits
+    pub const fn chunks(self, bits: u32) -> u32 {
+        bits / self.chunk_bits
+    }
+
+    /// the number of index bits, one less than the chunk size per chunk
+    const fn data_bits(self, bits: u32) -> u32 {
+        self.chunks(bits) * (self.chunk_bits - 1)
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub const fn capacity(self, bits: u32) -> u128 {
+        1 << self.data_bits(bits)
+    }
+
+    /// the bit that gives `data` the requested parity
+    const fn parity_bit(self, data: u128) -> u128 {
+        ((data.count_ones() + self.odd as u32) % 2) as u128
+    }
+
+    /// the lowest chunk of `code` without the requested parity, counting from the least
+    /// significant chunk
+    pub fn failing_chunk(self, code: u128, bits: u32) -> Option<u32> {
+        let mask = (1 << self.chunk_bits) - 1;
+        (0..self.chunks(bits)).find(|&chunk| {
+            let ones = (code >> (chunk * self.chunk_bits) & mask).count_ones();
+            ones % 2 != u32::from(self.odd)
+        })
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        if index.checked_shr(self.data_bits(bits)).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        let mask = (1 << (self.chunk_bits - 1)) - 1;
+        let code = (0..self.chunks(bits)).fold(0, |code, chunk| {
+            let data = index >> (chunk * (self.chunk_bits - 1)) & mask;
+            code | (data << 1 | self.parity_bit(data)) << (chunk * self.chunk_bits)
+        });
+        Some(code)
+    }
+
+    /// the index of `code`, or `None` if a chunk has the wrong parity
+    pub fn decode(self, code: u128, bits: u32) -> Option<u128> {
+        if self.failing_chunk(code, bits).is_some() {
+            return None;
+        }
+
+        let mask = (1 << self.chunk_bits) - 1;
+        let index = (0..self.chunks(bits)).fold(0, |index, chunk| {
+            let data = (code >> (chunk * self.chunk_bits) & mask) >> 1;
+            index | data << (chunk * (self.chunk_bits - 1))
+        });
+        Some(index)
+    }
+}
+
+impl Display for Chunked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, granularity = \"{}\"",
+            self.parity_name(),
+            self.granularity
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chunked, Granularity};
+
+    fn even_bytes() -> Chunked {
+        Chunked::new(false, Granularity::Byte).unwrap()
+    }
+
+    #[test]
+    pub fn small_even_bytes() {
+        let chunked = even_bytes();
+        let codes = [0, 1, 2, 3, 127, 128, 129].map(|index| chunked.encode(index, 16).unwrap());
+
+        assert_eq!(
+            codes,
+            [0x0000, 0x0003, 0x0005, 0x0006, 0x00ff, 0x0300, 0x0303]
+        );
+        assert_eq!(chunked.encode(0x4000, 16), None);
+    }
+
+    #[test]
+    pub fn ascending() {
+        let chunked = Chunked::new(true, Granularity::Nibble).unwrap();
+        let codes = (0..64)
+            .map(|index| chunked.encode(index, 8).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(codes.is_sorted());
+        assert_eq!(codes[..4], [0x11, 0x12, 0x14, 0x17]);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let chunked = even_bytes();
+        for index in [0, 1, 0x1234, 0xff_ffff] {
+            let code = chunked.encode(index, 32).unwrap();
+            assert_eq!(chunked.decode(code, 32), Some(index));
+        }
+    }
+
+    #[test]
+    pub fn one_flip_per_byte_detected() {
+        let chunked = even_bytes();
+        let code = chunked.encode(0x1234, 32).unwrap();
+
+        // two flips pass whole-word parity, but not parity in every byte
+        assert_eq!(chunked.failing_chunk(code ^ 0x0101, 32), Some(0));
+        assert_eq!(chunked.failing_chunk(code ^ 0x0100_0100, 32), Some(1));
+        assert_eq!(chunked.decode(code ^ 0x0100_0100, 32), None);
+    }
+}
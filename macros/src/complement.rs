@@ -0,0 +1,64 @@
+//! Complement pair discriminants
+//!
+//! The variant index is in the low half, and its bitwise complement is in the high half, as in the
+//! `0x5a`/`0xa5` pairs of safety-critical code. Any flipped bit breaks the pair, and a
+//! discriminant is never all zeros or all ones.
+
+/// the number of bits in each half
+const fn half_bits(bits: u32) -> u32 {
+    bits / 2
+}
+
+/// the number of codewords that fit in `bits` bits
+pub const fn capacity(bits: u32) -> u128 {
+    1 << half_bits(bits)
+}
+
+pub fn encode(index: u128, bits: u32) -> Option<u128> {
+    let half_bits = half_bits(bits);
+    if index.checked_shr(half_bits).unwrap_or(0) != 0 {
+        return None;
+    }
+
+    let mask = u128::MAX >> (u128::BITS - half_bits);
+    Some(index | (!index & mask) << half_bits)
+}
+
+/// the index of `code`, or `None` if its high half is not the complement of its low half
+pub fn decode(code: u128, bits: u32) -> Option<u128> {
+    let index = code & (u128::MAX >> (u128::BITS - half_bits(bits)));
+    (encode(index, bits) == Some(code)).then_some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+
+    #[test]
+    pub fn small_complement() {
+        let codes = (0..3).map(|index| encode(index, 16).unwrap());
+
+        assert!(codes.eq([0xff00, 0xfe01, 0xfd02]));
+        assert_eq!(encode(0x5a, 16), Some(0xa55a));
+        assert_eq!(encode(0x100, 16), None);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for index in [0, 1, 0x1234, 0xffff_ffff] {
+            let code = encode(index, 64).unwrap();
+            assert_eq!(decode(code, 64), Some(index));
+            for bit in 0..64 {
+                assert_eq!(decode(code ^ 1 << bit, 64), None);
+            }
+        }
+    }
+
+    #[test]
+    pub fn u128_halves() {
+        let code = encode(u128::from(u64::MAX), 128).unwrap();
+
+        assert_eq!(code, u128::from(u64::MAX));
+        assert_eq!(decode(u128::MAX, 128), None);
+    }
+}
@@ -0,0 +1,112 @@
+//! Distance constraints between specific variants
+//!
+//! A variant marked with `#[parity(distance_from(Other) >= N)]` differs from `Other` in at least
+//! `N` bits. Constraints are symmetric, so whichever of the two is assigned later is kept away
+//! from the other.
+
+use proc_macro2::Span;
+use syn::{
+    Ident, ItemEnum, LitInt, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// `distance_from(Other) >= N`, as written in the attribute
+struct DistanceFrom {
+    other: Ident,
+    distance: LitInt,
+}
+
+impl Parse for DistanceFrom {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = input.parse::<Ident>()?;
+        if keyword != "distance_from" {
+            return Err(syn::Error::new(
+                keyword.span(),
+                "expected `distance_from(Variant) >= N`",
+            ));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let other = content.parse()?;
+        input.parse::<Token![>=]>()?;
+        let distance = input.parse()?;
+
+        Ok(Self { other, distance })
+    }
+}
+
+/// Two variants that differ in at least `distance` bits
+#[derive(Clone, Debug)]
+pub struct Constraint {
+    /// the index of the variant with the attribute
+    variant: usize,
+    /// the index of the variant it names
+    other: usize,
+    pub distance: u32,
+    pub span: Span,
+}
+
+impl Constraint {
+    /// The indices of both variants
+    pub const fn pair(&self) -> (usize, usize) {
+        (self.variant, self.other)
+    }
+
+    /// The index of the variant `index` is constrained against, if it is either one of the pair
+    pub const fn partner(&self, index: usize) -> Option<usize> {
+        if self.variant == index {
+            Some(self.other)
+        } else if self.other == index {
+            Some(self.variant)
+        } else {
+            None
+        }
+    }
+}
+
+/// Removes every `#[parity(..)]` attribute from the variants, and returns their constraints
+pub fn take_constraints(enum_item: &mut ItemEnum) -> syn::Result<Vec<Constraint>> {
+    let idents = enum_item
+        .variants
+        .iter()
+        .map(|variant| variant.ident.clone())
+        .collect::<Vec<_>>();
+
+    let mut constraints = Vec::new();
+    for (index, variant) in enum_item.variants.iter_mut().enumerate() {
+        let (attrs, rest) = variant
+            .attrs
+            .drain(..)
+            .partition::<Vec<_>, _>(|attr| attr.path().is_ident("parity"));
+        variant.attrs = rest;
+
+        for attr in attrs {
+            let parsed =
+                attr.parse_args_with(Punctuated::<DistanceFrom, Token![,]>::parse_terminated)?;
+            for DistanceFrom { other, distance } in parsed {
+                let Some(other_index) = idents.iter().position(|ident| *ident == other) else {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        format!("no variant named `{other}`"),
+                    ));
+                };
+                if other_index == index {
+                    return Err(syn::Error::new(
+                        other.span(),
+                        "a variant cannot be constrained against itself",
+                    ));
+                }
+
+                constraints.push(Constraint {
+                    variant: index,
+                    other: other_index,
+                    distance: distance.base10_parse()?,
+                    span: distance.span(),
+                });
+            }
+        }
+    }
+
+    Ok(constraints)
+}
@@ -0,0 +1,319 @@
+//! CRC protected discriminants
+//!
+//! The variant index is in the high bits, followed by the CRC of the index in the low bits. The
+//! index bits are the message, fed in from the highest bit down, or from the lowest bit up if the
+//! input is reflected. So the CRC of a whole number of bytes matches the catalogued CRC of those
+//! bytes, in big-endian order, or little-endian order if reflected.
+
+use std::fmt::Display;
+
+use darling::FromMeta;
+
+/// A CRC, with the parameters it is catalogued by
+#[derive(Copy, Clone, Debug)]
+pub struct Crc {
+    /// the polynomial, without the implicit `x^width` term
+    poly: u64,
+    width: u32,
+    /// the value the register starts with
+    init: u64,
+    /// whether the index is fed in from the lowest bit up
+    refin: bool,
+    /// whether the register is reversed before `xorout`
+    refout: bool,
+    /// the value the CRC is finally combined with, by exclusive or
+    xorout: u64,
+    /// the name of the preset this came from, if any
+    name: Option<&'static str>,
+}
+
+impl Crc {
+    const PRESETS: &[Self] = &[
+        Self::preset("crc3-gsm", 0x3, 3, 0x0, false, 0x7),
+        Self::preset("crc4-itu", 0x3, 4, 0x0, true, 0x0),
+        Self::preset("crc5-usb", 0x05, 5, 0x1f, true, 0x1f),
+        Self::preset("crc6-itu", 0x03, 6, 0x00, true, 0x00),
+        Self::preset("crc7-mmc", 0x09, 7, 0x00, false, 0x00),
+        Self::preset("crc8-smbus", 0x07, 8, 0x00, false, 0x00),
+        Self::preset("crc8-autosar", 0x2f, 8, 0xff, false, 0xff),
+    ];
+
+    /// A catalogued CRC, every one of which reflects both its input and output, or neither
+    const fn preset(
+        name: &'static str,
+        poly: u64,
+        width: u32,
+        init: u64,
+        reflect: bool,
+        xorout: u64,
+    ) -> Self {
+        Self {
+            poly,
+            width,
+            init,
+            refin: reflect,
+            refout: reflect,
+            xorout,
+            name: Some(name),
+        }
+    }
+
+    /// the number of bits in the CRC
+    pub const fn width(self) -> u32 {
+        self.width
+    }
+
+    /// the number of index bits that fit alongside the CRC in `bits` bits
+    const fn data_bits(self, bits: u32) -> u32 {
+        bits.saturating_sub(self.width)
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub const fn capacity(self, bits: u32) -> u128 {
+        if self.width > bits {
+            return 0;
+        }
+        1 << self.data_bits(bits)
+    }
+
+    /// the CRC of the lowest `data_bits` bits of `index`
+    fn checksum(self, index: u128, data_bits: u32) -> u128 {
+        let top = 1 << (self.width - 1);
+        let ones = u128::MAX >> (u128::BITS - self.width);
+        let mut reg = u128::from(self.init);
+        for step in 0..data_bits {
+            let bit = if self.refin {
+                step
+            } else {
+                data_bits - 1 - step
+            };
+            let feedback = (index >> bit & 1 == 1) != (reg & top != 0);
+            reg = reg << 1 & ones;
+            if feedback {
+                reg ^= u128::from(self.poly);
+            }
+        }
+        if self.refout {
+            reg = reg.reverse_bits() >> (u128::BITS - self.width);
+        }
+        reg ^ u128::from(self.xorout)
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        if self.width > bits {
+            return None;
+        }
+        let data_bits = self.data_bits(bits);
+        if index.checked_shr(data_bits).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        Some(index << self.width | self.checksum(index, data_bits))
+    }
+
+    /// the index of `code`, or `None` if the CRC does not match
+    pub fn decode(self, code: u128, bits: u32) -> Option<u128> {
+        if self.width > bits {
+            return None;
+        }
+        let index = code >> self.width;
+        let crc = code & ((1 << self.width) - 1);
+
+        (self.checksum(index, self.data_bits(bits)) == crc).then_some(index)
+    }
+}
+
+impl FromMeta for Crc {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Self::PRESETS
+            .iter()
+            .find(|crc| crc.name == Some(value))
+            .copied()
+            .ok_or_else(|| {
+                darling::Error::custom(format!(
+                    "Unsupported CRC. Supported CRCs are {}",
+                    Self::PRESETS
+                        .iter()
+                        .filter_map(|crc| crc.name)
+                        .map(|name| format!("`{name}`"))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                ))
+            })
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct CustomCrc {
+            poly: u64,
+            width: u32,
+            #[darling(default)]
+            init: u64,
+            #[darling(default)]
+            refin: bool,
+            #[darling(default)]
+            refout: bool,
+            #[darling(default)]
+            xorout: u64,
+        }
+
+        let CustomCrc {
+            poly,
+            width,
+            init,
+            refin,
+            refout,
+            xorout,
+        } = CustomCrc::from_list(items)?;
+        if !(1..=64).contains(&width) {
+            return Err(darling::Error::custom(
+                "CRC `width` must be between 1 and 64",
+            ));
+        }
+        // the `x^width` term is implied, but may be given explicitly
+        if u128::from(poly) >> width > 1 {
+            return Err(darling::Error::custom(format!(
+                "CRC `poly` has terms above `x^{width}`"
+            )));
+        }
+        for (name, value) in [("init", init), ("xorout", xorout)] {
+            if u128::from(value) >> width != 0 {
+                return Err(darling::Error::custom(format!(
+                    "CRC `{name}` does not fit in {width} bits"
+                )));
+            }
+        }
+
+        Ok(Self {
+            poly: poly & (u64::MAX >> (u64::BITS - width)),
+            width,
+            init,
+            refin,
+            refout,
+            xorout,
+            name: None,
+        })
+    }
+}
+
+impl Display for Crc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = self.name {
+            return write!(f, "crc = \"{name}\"");
+        }
+        write!(f, "crc(poly = {:#x}, width = {}", self.poly, self.width)?;
+        if self.init != 0 {
+            write!(f, ", init = {:#x}", self.init)?;
+        }
+        if self.refin {
+            write!(f, ", refin = true")?;
+        }
+        if self.refout {
+            write!(f, ", refout = true")?;
+        }
+        if self.xorout != 0 {
+            write!(f, ", xorout = {:#x}", self.xorout)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+
+    use super::Crc;
+
+    fn crc4_itu() -> Crc {
+        Crc::from_string("crc4-itu").unwrap()
+    }
+
+    /// the CRC of the ASCII string "123456789", that each CRC is catalogued with
+    fn check_value(crc: Crc) -> u128 {
+        let mut message = [0; 16];
+        message[..9].copy_from_slice(b"123456789");
+        let index = if crc.refin {
+            u128::from_le_bytes(message)
+        } else {
+            u128::from_be_bytes(message) >> (128 - 72)
+        };
+        crc.checksum(index, 72)
+    }
+
+    #[test]
+    pub fn check_values() {
+        let checks = [
+            ("crc3-gsm", 0x4),
+            ("crc4-itu", 0x7),
+            ("crc5-usb", 0x19),
+            ("crc6-itu", 0x06),
+            ("crc7-mmc", 0x75),
+            ("crc8-smbus", 0xf4),
+            ("crc8-autosar", 0xdf),
+        ];
+        for (name, check) in checks {
+            let crc = Crc::from_string(name).unwrap();
+            assert_eq!(check_value(crc), check, "{name}");
+        }
+        assert_eq!(checks.len(), Crc::PRESETS.len());
+    }
+
+    #[test]
+    pub fn crc4_itu_u8() {
+        let crc = crc4_itu();
+        let codes = (0..4).map(|index| crc.encode(index, 8).unwrap());
+
+        assert!(codes.eq([0x00, 0x1d, 0x23, 0x3e]));
+        assert_eq!(crc.encode(16, 8), None);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        let crc = Crc::from_string("crc8-autosar").unwrap();
+        for index in [0, 1, 0x1234, 0xff_ffff] {
+            let code = crc.encode(index, 32).unwrap();
+            assert_eq!(crc.decode(code, 32), Some(index));
+        }
+    }
+
+    #[test]
+    pub fn single_bit_errors_detected() {
+        let crc = crc4_itu();
+        let code = crc.encode(0x0b, 8).unwrap();
+        for bit in 0..8 {
+            assert_eq!(crc.decode(code ^ 1 << bit, 8), None);
+        }
+    }
+
+    #[test]
+    pub fn custom_poly_with_top_term() {
+        let items = [syn::parse_quote!(poly = 0x13), syn::parse_quote!(width = 4)];
+        let crc = Crc::from_list(&items).unwrap();
+
+        // the plain polynomial remainder, so the top 4 bits are divisible by `x^4 + x + 1`
+        assert!(
+            (0..4)
+                .map(|index| crc.encode(index, 8).unwrap())
+                .eq([0x00, 0x13, 0x26, 0x35])
+        );
+    }
+
+    #[test]
+    pub fn custom_matches_preset() {
+        let items = [
+            syn::parse_quote!(poly = 0x05),
+            syn::parse_quote!(width = 5),
+            syn::parse_quote!(init = 0x1f),
+            syn::parse_quote!(refin = true),
+            syn::parse_quote!(refout = true),
+            syn::parse_quote!(xorout = 0x1f),
+        ];
+        let crc = Crc::from_list(&items).unwrap();
+
+        assert_eq!(check_value(crc), 0x19);
+        assert_eq!(
+            crc.to_string(),
+            "crc(poly = 0x5, width = 5, init = 0x1f, refin = true, refout = true, xorout = 0x1f)"
+        );
+    }
+}
@@ -0,0 +1,523 @@
+//! Methods generated on the enum from its assigned discriminants
+//!
+//! These construct variants from raw values, so they are only generated for enums without fields.
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Attribute, Fields, ItemEnum};
+
+use crate::{bit_par_iter::Remaining, check_matrix::MinDistance, int_repr::IntRepr};
+
+/// Wraps `items` in an inherent `impl` block for `enum_item`
+pub fn impl_block(enum_item: &ItemEnum, items: &TokenStream) -> TokenStream {
+    let ident = &enum_item.ident;
+    let (impl_generics, ty_generics, where_clause) = enum_item.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #items
+        }
+    }
+}
+
+/// `MIN_DISTANCE`, the smallest number of bits that any two discriminants differ in
+pub fn min_distance(enum_item: &ItemEnum, min_distance: MinDistance) -> TokenStream {
+    let vis = &enum_item.vis;
+    let (doc, min_distance) = match min_distance {
+        MinDistance::Exact(min_distance) => (
+            "The smallest number of bits that any two discriminants differ in",
+            min_distance,
+        ),
+        MinDistance::AtLeast(min_distance) => (
+            "The smallest number of bits that any two discriminants differ in\n\n\
+             This is a lower bound, since there were too many to search through",
+            min_distance,
+        ),
+    };
+
+    quote! {
+        #[doc = #doc]
+        #vis const MIN_DISTANCE: u32 = #min_distance;
+    }
+}
+
+/// `REMAINING_CAPACITY`, the number of variants that could still be added under the same scheme
+pub fn remaining_capacity(enum_item: &ItemEnum, remaining: Remaining) -> TokenStream {
+    let vis = &enum_item.vis;
+    let (doc, remaining) = match remaining {
+        Remaining::Exact(remaining) => (
+            "How many more variants fit in this repr, when they are added at the end",
+            remaining,
+        ),
+        Remaining::AtLeast(remaining) => (
+            "How many more variants fit in this repr, when they are added at the end\n\n\
+             This is a lower bound, since there were too many to search through",
+            remaining,
+        ),
+    };
+    let remaining = Literal::u128_unsuffixed(remaining);
+
+    quote! {
+        #[doc = #doc]
+        #vis const REMAINING_CAPACITY: u128 = #remaining;
+    }
+}
+
+/// A variant and its assigned discriminant
+struct Entry {
+    ident: Ident,
+    /// the `cfg` attributes on the variant, which also apply to anything generated for it
+    cfgs: Vec<Attribute>,
+    /// the discriminant, as a suffixed literal
+    value: TokenStream,
+}
+
+/// The discriminants assigned to every variant of a fieldless enum
+pub struct Codebook<'a> {
+    enum_item: &'a ItemEnum,
+    repr: Ident,
+    /// the unsigned repr of the same width, which the bits of a discriminant are compared in
+    unsigned: Ident,
+    entries: Vec<Entry>,
+}
+
+impl<'a> Codebook<'a> {
+    /// `None` if any variant has fields
+    pub fn new(enum_item: &'a ItemEnum, repr: IntRepr, values: Vec<TokenStream>) -> Option<Self> {
+        if enum_item
+            .variants
+            .iter()
+            .any(|variant| !matches!(variant.fields, Fields::Unit))
+        {
+            return None;
+        }
+
+        let entries = enum_item
+            .variants
+            .iter()
+            .zip(values)
+            .map(|(variant, value)| Entry {
+                ident: variant.ident.clone(),
+                cfgs: variant
+                    .attrs
+                    .iter()
+                    .filter(|attr| attr.path().is_ident("cfg"))
+                    .cloned()
+                    .collect(),
+                value,
+            })
+            .collect();
+
+        Some(Self {
+            enum_item,
+            repr: format_ident!("{repr}"),
+            unsigned: format_ident!("{}", repr.unsigned().to_string()),
+            entries,
+        })
+    }
+
+    /// `from_repr` and `to_repr`, and `VARIANTS` and `DISCRIMINANTS` in declaration order, which
+    /// all work in `const` contexts
+    pub fn repr_consts(&self) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let checks = self.entries.iter().map(|Entry { ident, cfgs, value }| {
+            quote! {
+                #(#cfgs)*
+                if raw == #value {
+                    return ::core::option::Option::Some(Self::#ident);
+                }
+            }
+        });
+        let variants = self
+            .entries
+            .iter()
+            .map(|Entry { ident, cfgs, .. }| quote!(#(#cfgs)* Self::#ident));
+        let discriminants = self
+            .entries
+            .iter()
+            .map(|Entry { cfgs, value, .. }| quote!(#(#cfgs)* #value));
+        // the variants that are configured out are left out of the length as well
+        let units = self
+            .entries
+            .iter()
+            .map(|Entry { cfgs, .. }| quote!(#(#cfgs)* ()));
+        let len = quote!([#(#units),*].len());
+
+        quote! {
+            /// Converts `raw` to its variant, or `None` if it is not a discriminant
+            #vis const fn from_repr(raw: #repr) -> ::core::option::Option<Self> {
+                #(#checks)*
+                ::core::option::Option::None
+            }
+
+            /// The discriminant of this variant
+            #vis const fn to_repr(self) -> #repr {
+                self as #repr
+            }
+
+            /// Every variant, in declaration order
+            #vis const VARIANTS: [Self; #len] = [#(#variants),*];
+
+            /// The discriminant of every variant, in declaration order
+            #vis const DISCRIMINANTS: [#repr; #len] = [#(#discriminants),*];
+        }
+    }
+
+    /// `CODES`, local to a generated method, with the bits of every discriminant, zero extended
+    /// from the repr
+    ///
+    /// These are cast on the target, like the `#repr::BITS` that the generated methods count bits
+    /// up to, so a `usize` discriminant is extended from the target's width rather than the host's.
+    fn codes_const(&self) -> TokenStream {
+        let unsigned = &self.unsigned;
+        let codes = self
+            .entries
+            .iter()
+            .map(|Entry { cfgs, value, .. }| quote!(#(#cfgs)* (#value as #unsigned as u128)));
+
+        quote!(const CODES: &[u128] = &[#(#codes),*];)
+    }
+
+    /// `VARIANTS` and `CODES`, local to a generated method, so that they can be borrowed as
+    /// candidates
+    fn candidate_consts(&self) -> TokenStream {
+        let ident = &self.enum_item.ident;
+        let codes = self.codes_const();
+
+        quote! {
+            const VARIANTS: &[#ident] = &#ident::VARIANTS;
+            #codes
+        }
+    }
+
+    /// `decode_nearest`, which maps any value to the variant whose discriminant differs from it in
+    /// the fewest bits, or to every variant that is equally near
+    pub fn decode_nearest(&self) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let unsigned = &self.unsigned;
+        let consts = self.candidate_consts();
+
+        quote! {
+            /// Decodes `raw` to the variant whose discriminant differs from it in the fewest bits
+            ///
+            /// If several variants are equally near, they are all returned as candidates, rather
+            /// than picking one of them.
+            #vis const fn decode_nearest(raw: #repr) -> ::enum_parity::Decoded<Self> {
+                #consts
+                let bits = raw as #unsigned as u128;
+                let mut nearest = 0;
+                let mut distance = u32::MAX;
+                let mut ties = 0;
+                let mut idx = 0;
+                while idx < CODES.len() {
+                    let diff = (CODES[idx] ^ bits).count_ones();
+                    if diff < distance {
+                        nearest = idx;
+                        distance = diff;
+                        ties = 0;
+                    }
+                    if diff == distance {
+                        ties += 1;
+                    }
+                    idx += 1;
+                }
+                if ties != 1 {
+                    let candidates =
+                        ::enum_parity::Candidates::new(VARIANTS, CODES, bits, u128::MAX, distance);
+                    return ::enum_parity::Decoded::Ambiguous { candidates };
+                }
+
+                let ::core::option::Option::Some(variant) = Self::from_repr(Self::DISCRIMINANTS[nearest]) else {
+                    unreachable!()
+                };
+                if distance == 0 {
+                    ::enum_parity::Decoded::Exact(variant)
+                } else {
+                    ::enum_parity::Decoded::Corrected {
+                        variant,
+                        flipped_mask: CODES[nearest] ^ bits,
+                    }
+                }
+            }
+        }
+    }
+
+    /// `decode_with_erasures`, which maps any value to the only variant whose discriminant agrees
+    /// with it outside of the erased bits
+    pub fn decode_with_erasures(&self) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let unsigned = &self.unsigned;
+        let consts = self.candidate_consts();
+
+        quote! {
+            /// Decodes `raw` to the only variant whose discriminant agrees with it in every bit that
+            /// is not set in `erased_mask`
+            ///
+            /// # Errors
+            /// Returns [`ErasureError::NoVariant`](::enum_parity::ErasureError::NoVariant) if no
+            /// variant agrees with the known bits, or
+            /// [`ErasureError::Ambiguous`](::enum_parity::ErasureError::Ambiguous) with every
+            /// variant that does, if there are several
+            #vis const fn decode_with_erasures(
+                raw: #repr,
+                erased_mask: #repr,
+            ) -> ::core::result::Result<Self, ::enum_parity::ErasureError<Self>> {
+                #consts
+                let bits = raw as #unsigned as u128;
+                let known = !(erased_mask as #unsigned) as u128;
+                let mut found = 0;
+                let mut matches = 0;
+                let mut idx = 0;
+                while idx < CODES.len() {
+                    if (CODES[idx] ^ bits) & known == 0 {
+                        found = idx;
+                        matches += 1;
+                    }
+                    idx += 1;
+                }
+
+                match matches {
+                    0 => ::core::result::Result::Err(::enum_parity::ErasureError::NoVariant),
+                    1 => match Self::from_repr(Self::DISCRIMINANTS[found]) {
+                        ::core::option::Option::Some(variant) => ::core::result::Result::Ok(variant),
+                        ::core::option::Option::None => unreachable!(),
+                    },
+                    _ => ::core::result::Result::Err(::enum_parity::ErasureError::Ambiguous(
+                        ::enum_parity::Candidates::new(VARIANTS, CODES, bits, known, 0),
+                    )),
+                }
+            }
+        }
+    }
+
+    /// `decode_soft`, which maps a log-likelihood ratio per bit to the most likely variant, and its
+    /// margin over the next most likely one
+    pub fn decode_soft(&self) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let codes = self.codes_const();
+
+        quote! {
+            /// Decodes a log-likelihood ratio per bit to the most likely variant, and the margin
+            /// to the runner-up
+            ///
+            /// `llrs[i]` is for bit `i`, counting from the least significant bit, and is positive
+            /// if that bit is more likely clear. Each variant scores the sum of these, negated for
+            /// the bits that are set in its discriminant. The margin is how much higher the best
+            /// score is than the next one, which is infinite if there is only one variant. Ties go
+            /// to the variant that is declared first, with a margin of zero.
+            #vis const fn decode_soft(llrs: &[f32; #repr::BITS as usize]) -> (Self, f32) {
+                #codes
+                let mut best = 0;
+                let mut best_score = f32::NEG_INFINITY;
+                let mut runner_up = f32::NEG_INFINITY;
+                let mut idx = 0;
+                while idx < CODES.len() {
+                    let mut score = 0.0;
+                    let mut bit = 0;
+                    while bit < llrs.len() {
+                        if CODES[idx] >> bit & 1 == 1 {
+                            score -= llrs[bit];
+                        } else {
+                            score += llrs[bit];
+                        }
+                        bit += 1;
+                    }
+                    if score > best_score {
+                        runner_up = best_score;
+                        best = idx;
+                        best_score = score;
+                    } else if score > runner_up {
+                        runner_up = score;
+                    }
+                    idx += 1;
+                }
+
+                match Self::from_repr(Self::DISCRIMINANTS[best]) {
+                    ::core::option::Option::Some(variant) => (variant, best_score - runner_up),
+                    ::core::option::Option::None => unreachable!(),
+                }
+            }
+        }
+    }
+
+    /// `decode`, which maps a discriminant to its variant, and rejects any value whose bits under
+    /// `mask` are not `tag`
+    pub fn decode_tagged(&self, mask: &TokenStream, tag: &TokenStream) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let checks = self.entries.iter().map(|Entry { ident, cfgs, value }| {
+            quote! {
+                #(#cfgs)*
+                if raw == #value {
+                    return ::core::option::Option::Some(Self::#ident);
+                }
+            }
+        });
+
+        quote! {
+            /// Converts `raw` to its variant, or `None` if it does not have this enum's tag, or is
+            /// not a discriminant
+            #vis const fn decode(raw: #repr) -> ::core::option::Option<Self> {
+                if raw & #mask != #tag {
+                    return ::core::option::Option::None;
+                }
+                #(#checks)*
+                ::core::option::Option::None
+            }
+        }
+    }
+
+    /// `from_decimal`, which parses the decimal form of a discriminant, and rejects it unless
+    /// `validate` accepts its digits in `bytes`
+    pub fn decimal_parser(&self, name: &str, validate: &TokenStream) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let checks = self.entries.iter().map(|Entry { ident, cfgs, value }| {
+            quote! {
+                #(#cfgs)*
+                if raw == #value {
+                    return ::core::option::Option::Some(Self::#ident);
+                }
+            }
+        });
+        let doc = format!(
+            "Parses the decimal form of a discriminant, or `None` if it is not only digits, does \
+             not end in a valid {name} check digit, or is not a discriminant"
+        );
+
+        quote! {
+            #[doc = #doc]
+            #vis const fn from_decimal(s: &str) -> ::core::option::Option<Self> {
+                let bytes = s.as_bytes();
+                if bytes.is_empty() {
+                    return ::core::option::Option::None;
+                }
+                let mut raw: #repr = 0;
+                let mut idx = 0;
+                while idx < bytes.len() {
+                    if !bytes[idx].is_ascii_digit() {
+                        return ::core::option::Option::None;
+                    }
+                    raw = match raw.checked_mul(10) {
+                        ::core::option::Option::Some(raw) => {
+                            match raw.checked_add((bytes[idx] - b'0') as #repr) {
+                                ::core::option::Option::Some(raw) => raw,
+                                ::core::option::Option::None => return ::core::option::Option::None,
+                            }
+                        }
+                        ::core::option::Option::None => return ::core::option::Option::None,
+                    };
+                    idx += 1;
+                }
+                if !#validate {
+                    return ::core::option::Option::None;
+                }
+                #(#checks)*
+                ::core::option::Option::None
+            }
+        }
+    }
+
+    /// `impl TryFrom<repr>`, which maps a discriminant to its variant, and tells whether any other
+    /// value passes `check`
+    pub fn try_from_impl(&self, check: &TokenStream) -> TokenStream {
+        let ident = &self.enum_item.ident;
+        let (impl_generics, ty_generics, where_clause) = self.enum_item.generics.split_for_impl();
+        let repr = &self.repr;
+        let checks = self.entries.iter().map(|Entry { ident, cfgs, value }| {
+            quote! {
+                #(#cfgs)*
+                if raw == #value {
+                    return ::core::result::Result::Ok(Self::#ident);
+                }
+            }
+        });
+
+        quote! {
+            impl #impl_generics ::core::convert::TryFrom<#repr> for #ident #ty_generics #where_clause {
+                type Error = ::enum_parity::ParityError<#repr>;
+
+                fn try_from(raw: #repr) -> ::core::result::Result<Self, Self::Error> {
+                    #(#checks)*
+                    if #check {
+                        ::core::result::Result::Err(::enum_parity::ParityError::NoVariant(raw))
+                    } else {
+                        ::core::result::Result::Err(::enum_parity::ParityError::InvalidParity(raw))
+                    }
+                }
+            }
+        }
+    }
+
+    /// `correct`, which maps any value within `radius` bits of a discriminant to its variant
+    pub fn correct(&self, radius: u32) -> TokenStream {
+        let doc = format!(
+            "Converts `raw` to its variant, correcting up to {radius} flipped bit{}",
+            if radius == 1 { "" } else { "s" }
+        );
+
+        self.correct_within(&doc, |value| quote!((raw ^ #value).count_ones() <= #radius))
+    }
+
+    /// `correct`, which maps any value within `radius` corrupted `symbol_bits` bit symbols of a
+    /// discriminant to its variant
+    pub fn correct_symbols(&self, radius: u32, symbol_bits: u32, bits: u32) -> TokenStream {
+        let symbols = bits / symbol_bits;
+        let mask = Literal::u128_unsuffixed((1 << symbol_bits) - 1);
+        let doc = format!(
+            "Converts `raw` to its variant, correcting up to {radius} corrupted {symbol_bits} bit \
+             symbol{}",
+            if radius == 1 { "" } else { "s" }
+        );
+
+        self.correct_within(&doc, |value| {
+            quote! {{
+                let diff = raw ^ #value;
+                let mut corrupted = 0;
+                let mut symbol = 0;
+                while symbol < #symbols {
+                    if (diff >> (symbol * #symbol_bits)) & #mask != 0 {
+                        corrupted += 1;
+                    }
+                    symbol += 1;
+                }
+                corrupted <= #radius
+            }}
+        })
+    }
+
+    /// `correct`, which returns the first variant whose discriminant is `within` reach of `raw`
+    fn correct_within(
+        &self,
+        doc: &str,
+        within: impl Fn(&TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let vis = &self.enum_item.vis;
+        let repr = &self.repr;
+        let checks = self.entries.iter().map(|Entry { ident, cfgs, value }| {
+            let within = within(value);
+            quote! {
+                #(#cfgs)*
+                if #within {
+                    return ::core::result::Result::Ok(Self::#ident);
+                }
+            }
+        });
+
+        quote! {
+            #[doc = #doc]
+            ///
+            /// # Errors
+            /// Returns [`Uncorrectable`](::enum_parity::Uncorrectable) if `raw` is not close
+            /// enough to any variant
+            #vis const fn correct(raw: #repr) -> ::core::result::Result<Self, ::enum_parity::Uncorrectable> {
+                #(#checks)*
+                ::core::result::Result::Err(::enum_parity::Uncorrectable)
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Gray code ordering
+//!
+//! Variant `i` is assigned the `i`-th reflected Gray code, so neighbouring variants differ in
+//! exactly one bit.
+//!
+//! A wrapping Gray code also has the last variant differ from the first in exactly one bit. For `n`
+//! variants, this uses the first and last `n / 2` codes of the smallest reflected Gray code that
+//! holds them, which only differ in their top bit at the seam.
+
+use std::{fmt::Display, ops::Range};
+
+use darling::FromMeta;
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Gray {
+    /// whether the last variant must be adjacent to the first
+    wrap: bool,
+    /// the number of variants, only used when wrapping
+    len: u128,
+}
+
+const fn to_gray(index: u128) -> u128 {
+    index ^ (index >> 1)
+}
+
+const fn from_gray(code: u128) -> u128 {
+    let mut index = code;
+    let mut shift = 1;
+    while shift < u128::BITS {
+        index ^= index >> shift;
+        shift <<= 1;
+    }
+    index
+}
+
+impl Gray {
+    pub const fn wraps(self) -> bool {
+        self.wrap
+    }
+
+    /// Sets the number of variants, which the wrapping code depends on
+    pub const fn with_len(self, len: u128) -> Self {
+        Self { len, ..self }
+    }
+
+    /// the distance between the two halves of a wrapping code
+    const fn gap(self) -> u128 {
+        self.len.next_power_of_two() - self.len
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        let index = if self.wrap && index >= self.len / 2 {
+            index.checked_add(self.gap())?
+        } else {
+            index
+        };
+        if index.checked_shr(bits).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        Some(to_gray(index))
+    }
+
+    /// the indices in between the two halves of a wrapping code, which are skipped
+    pub const fn skipped(self) -> Range<u128> {
+        self.len / 2..self.len / 2 + self.gap()
+    }
+
+    /// the index of `code`, or `None` if it is skipped by a wrapping code
+    pub fn decode(self, code: u128) -> Option<u128> {
+        let index = from_gray(code);
+        if !self.wrap || index < self.len / 2 {
+            Some(index)
+        } else {
+            index
+                .checked_sub(self.gap())
+                .filter(|&index| index >= self.len / 2)
+        }
+    }
+}
+
+impl FromMeta for Gray {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self::default())
+    }
+
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct GrayOptions {
+            wrap: darling::util::Flag,
+        }
+
+        let GrayOptions { wrap } = GrayOptions::from_list(items)?;
+        Ok(Self {
+            wrap: wrap.is_present(),
+            ..Self::default()
+        })
+    }
+}
+
+impl Display for Gray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.wrap {
+            write!(f, "gray(wrap)")
+        } else {
+            write!(f, "gray")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gray, from_gray, to_gray};
+
+    fn wrapping(len: u128) -> Gray {
+        Gray { wrap: true, len }
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for index in [0, 1, 2, 3, 0xff, u128::MAX] {
+            assert_eq!(from_gray(to_gray(index)), index);
+        }
+    }
+
+    #[test]
+    pub fn small_gray() {
+        let codes = (0..8).map(|index| Gray::default().encode(index, 8).unwrap());
+
+        assert!(codes.eq([0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]));
+    }
+
+    #[test]
+    pub fn wrapping_six() {
+        let gray = wrapping(6);
+        let codes = (0..6)
+            .map(|index| gray.encode(index, 8).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(codes, [0b000, 0b001, 0b011, 0b111, 0b101, 0b100]);
+        for (idx, code) in codes.iter().enumerate() {
+            let next = codes[(idx + 1) % codes.len()];
+            assert_eq!((code ^ next).count_ones(), 1);
+            assert_eq!(gray.decode(*code), Some(idx as u128));
+        }
+        // skipped by the wrapping code
+        assert_eq!(gray.decode(0b010), None);
+        assert_eq!(gray.decode(0b110), None);
+    }
+
+    #[test]
+    pub fn out_of_range() {
+        assert_eq!(Gray::default().encode(0x100, 8), None);
+        assert_eq!(wrapping(256).encode(0xff, 8), Some(0x80));
+        assert_eq!(wrapping(256).encode(0x100, 8), None);
+    }
+}
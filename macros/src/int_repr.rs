@@ -23,6 +23,30 @@ impl IntRepr {
     const ALL_FMT: &[&str] = &[
         "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
     ];
+
+    pub const fn is_signed(self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize
+        )
+    }
+
+    /// Whether the width is that of a pointer on the target, which the macro cannot know
+    pub const fn is_pointer_width(self) -> bool {
+        matches!(self, Self::Usize | Self::Isize)
+    }
+
+    /// The unsigned repr of the same width
+    pub const fn unsigned(self) -> Self {
+        match self {
+            Self::U8 | Self::I8 => Self::U8,
+            Self::U16 | Self::I16 => Self::U16,
+            Self::U32 | Self::I32 => Self::U32,
+            Self::U64 | Self::I64 => Self::U64,
+            Self::U128 | Self::I128 => Self::U128,
+            Self::Usize | Self::Isize => Self::Usize,
+        }
+    }
 }
 
 impl FromStr for IntRepr {
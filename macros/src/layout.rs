@@ -0,0 +1,130 @@
+//! A single parity bit at a fixed position
+//!
+//! The variant index is in the remaining bits, so discriminants can be computed by hand. With
+//! `parity_lsb`, variant `i` is `(i << 1) | p`, and with `parity_msb` it is `i | p << (W - 1)`,
+//! where `p` gives the whole discriminant the requested parity.
+
+use std::fmt::Display;
+
+use darling::FromMeta;
+
+/// Where the parity bit goes
+#[derive(Copy, Clone, Debug, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum Layout {
+    ParityMsb,
+    ParityLsb,
+}
+
+impl Display for Layout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ParityMsb => write!(f, "parity_msb"),
+            Self::ParityLsb => write!(f, "parity_lsb"),
+        }
+    }
+}
+
+/// Even or odd parity, with the parity bit at a fixed position
+#[derive(Copy, Clone, Debug)]
+pub struct ParityBit {
+    odd: bool,
+    layout: Layout,
+}
+
+impl ParityBit {
+    pub const fn new(odd: bool, layout: Layout) -> Self {
+        Self { odd, layout }
+    }
+
+    pub const fn layout(self) -> Layout {
+        self.layout
+    }
+
+    /// the position of the parity bit in `bits` bits
+    pub const fn position(self, bits: u32) -> u32 {
+        match self.layout {
+            Layout::ParityMsb => bits - 1,
+            Layout::ParityLsb => 0,
+        }
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub const fn capacity(bits: u32) -> u128 {
+        1 << (bits - 1)
+    }
+
+    /// the parity bit that variant `index` needs
+    pub const fn parity_bit(self, index: u128) -> u128 {
+        ((index.count_ones() + self.odd as u32) % 2) as u128
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        if index.checked_shr(bits - 1).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        let parity = self.parity_bit(index);
+        Some(match self.layout {
+            Layout::ParityMsb => index | parity << (bits - 1),
+            Layout::ParityLsb => index << 1 | parity,
+        })
+    }
+
+    /// The index bits of `code`, whether or not its parity bit is correct
+    pub const fn index(self, code: u128, bits: u32) -> u128 {
+        match self.layout {
+            Layout::ParityMsb => code & !(1 << (bits - 1)),
+            Layout::ParityLsb => code >> 1,
+        }
+    }
+
+    /// the index of `code`, or `None` if its parity bit is wrong
+    pub fn decode(self, code: u128, bits: u32) -> Option<u128> {
+        let index = self.index(code, bits);
+        (self.encode(index, bits) == Some(code)).then_some(index)
+    }
+}
+
+impl Display for ParityBit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parity = if self.odd { "odd" } else { "even" };
+        write!(f, "{parity}, layout = \"{}\"", self.layout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Layout, ParityBit};
+
+    #[test]
+    pub fn parity_msb() {
+        let even = ParityBit::new(false, Layout::ParityMsb);
+        let codes = (0..4).map(|index| even.encode(index, 8).unwrap());
+
+        assert!(codes.eq([0x00, 0x81, 0x82, 0x03]));
+        assert_eq!(even.encode(0x7f, 8), Some(0xff));
+        assert_eq!(even.encode(0x80, 8), None);
+    }
+
+    #[test]
+    pub fn parity_lsb() {
+        let odd = ParityBit::new(true, Layout::ParityLsb);
+        let codes = (0..4).map(|index| odd.encode(index, 8).unwrap());
+
+        assert!(codes.eq([0x01, 0x02, 0x04, 0x07]));
+        assert_eq!(odd.encode(0x80, 8), None);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for layout in [Layout::ParityMsb, Layout::ParityLsb] {
+            let odd = ParityBit::new(true, layout);
+            for index in [0, 1, 0x1234, 0x7fff_ffff] {
+                let code = odd.encode(index, 32).unwrap();
+                assert_eq!(odd.decode(code, 32), Some(index));
+                assert_eq!(odd.decode(code ^ 1 << 7, 32), None);
+            }
+        }
+    }
+}
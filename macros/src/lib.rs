@@ -0,0 +1,2367 @@
+//! Procedural macro implementation for the [`enum_parity`](https://docs.rs/enum_parity) crate
+//!
+//! This crate should not be used directly, use `enum_parity` instead.
+
+mod balanced;
+mod bch;
+mod berger;
+mod bit_par_iter;
+mod burst;
+mod check_digit;
+mod check_matrix;
+mod chunked;
+mod complement;
+mod constraint;
+mod crc;
+mod generate;
+mod gray;
+mod int_repr;
+mod layout;
+mod name_hash;
+mod parity_check;
+mod plotkin;
+mod reed_solomon;
+mod repeated;
+mod secded;
+
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use balanced::Balanced;
+use bch::Bch;
+use bit_par_iter::{BitParityIter, IntegerParity, Remaining};
+use check_digit::CheckDigit;
+use check_matrix::{CheckMatrix, MinDistance};
+use chunked::{Chunked, Granularity};
+use constraint::Constraint;
+use crc::Crc;
+use darling::{FromAttributes, FromMeta, util::SpannedValue};
+use generate::{Codebook, impl_block};
+use gray::Gray;
+use int_repr::IntRepr;
+use itertools::Itertools;
+use layout::{Layout, ParityBit};
+use name_hash::Assign;
+use parity_check::ParityCheck;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use reed_solomon::ReedSolomon;
+use syn::{Expr, Ident, ItemEnum, Variant, parse_macro_input, spanned::Spanned};
+
+#[derive(Clone, Debug, FromMeta)]
+enum Parity {
+    Even,
+    Odd,
+    /// exactly this many bits set, i.e. an m-of-n code
+    Weight(u32),
+    /// the variant index, followed by the number of zeros in the index
+    Berger,
+    /// the variant index, followed by its CRC
+    Crc(Crc),
+    /// the variant index as a Gray code, so neighbouring variants differ in one bit
+    Gray(Gray),
+    /// exactly half of the bits set
+    Balanced(Balanced),
+    /// the variant index, followed by interleaved parity that detects bursts up to this many bits
+    Burst(u32),
+    /// the variant index in the low half, and its complement in the high half
+    Complement,
+    /// the variant index in decimal, followed by a check digit
+    CheckDigit(CheckDigit),
+    /// `even` or `odd` in every byte or nibble, set from `granularity`
+    #[darling(skip)]
+    Chunked(Chunked),
+    /// `even` or `odd`, with the parity bit at a fixed position, set from `layout`
+    #[darling(skip)]
+    ParityBit(ParityBit),
+    /// a short value repeated this many times, searched by `maximize_distance`
+    #[darling(skip)]
+    Repeated(u32),
+    /// a code that corrects errors, which is parsed on its own
+    #[darling(skip)]
+    Correcting(Correcting),
+}
+
+impl Parity {
+    /// Maps a position in the code to its codeword
+    ///
+    /// Systematic codes count through the variant indices, every other parity counts through the
+    /// values directly.
+    fn encode(&self, cursor: u128, bits: u32) -> Option<u128> {
+        match self {
+            Self::Even | Self::Odd | Self::Weight(_) => Some(cursor),
+            Self::Berger => berger::encode(cursor, bits),
+            Self::Crc(crc) => crc.encode(cursor, bits),
+            Self::Gray(gray) => gray.encode(cursor, bits),
+            Self::Balanced(balanced) => balanced.encode(cursor, bits),
+            Self::Burst(burst) => burst::encode(cursor, *burst, bits),
+            Self::Complement => complement::encode(cursor, bits),
+            Self::CheckDigit(check_digit) => check_digit.encode(cursor, bits),
+            Self::Chunked(chunked) => chunked.encode(cursor, bits),
+            Self::ParityBit(parity_bit) => parity_bit.encode(cursor, bits),
+            Self::Repeated(copies) => repeated::encode(cursor, *copies, bits),
+            Self::Correcting(code) => code.encode(cursor, bits),
+        }
+    }
+
+    /// The inverse of [`Parity::encode`], or `None` if `val` is not a codeword
+    fn decode(&self, val: u128, bits: u32) -> Option<u128> {
+        match self {
+            Self::Even | Self::Odd | Self::Weight(_) => Some(val),
+            Self::Berger => berger::decode(val, bits),
+            Self::Crc(crc) => crc.decode(val, bits),
+            Self::Gray(gray) => gray.decode(val),
+            Self::Balanced(balanced) => balanced.decode(val, bits),
+            Self::Burst(burst) => burst::decode(val, *burst, bits),
+            Self::Complement => complement::decode(val, bits),
+            Self::CheckDigit(check_digit) => check_digit.decode(val, bits),
+            Self::Chunked(chunked) => chunked.decode(val, bits),
+            Self::ParityBit(parity_bit) => parity_bit.decode(val, bits),
+            Self::Repeated(copies) => repeated::decode(val, *copies, bits),
+            Self::Correcting(code) => code.decode(val, bits),
+        }
+    }
+
+    /// The number of codewords that fit in `bits` bits, if it is worth reporting
+    fn capacity(&self, bits: u32) -> Option<u128> {
+        match self {
+            Self::Even | Self::Odd | Self::Weight(_) | Self::Gray(_) | Self::Repeated(_) => None,
+            Self::Berger => Some(berger::capacity(bits)),
+            Self::Crc(crc) => Some(crc.capacity(bits)),
+            Self::Balanced(balanced) => balanced.capacity(bits),
+            Self::Burst(burst) => Some(burst::capacity(*burst, bits)),
+            Self::Complement => Some(complement::capacity(bits)),
+            Self::CheckDigit(check_digit) => Some(check_digit.capacity(bits)),
+            Self::Chunked(chunked) => Some(chunked.capacity(bits)),
+            Self::ParityBit(_) => Some(ParityBit::capacity(bits)),
+            Self::Correcting(code) => Some(code.capacity(bits)),
+        }
+    }
+}
+
+// TODO could probably get rid of this for some provided method from darling?
+impl Display for Parity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Even => write!(f, "even"),
+            Self::Odd => write!(f, "odd"),
+            Self::Weight(weight) => write!(f, "weight = {weight}"),
+            Self::Berger => write!(f, "berger"),
+            Self::Crc(crc) => write!(f, "{crc}"),
+            Self::Gray(gray) => write!(f, "{gray}"),
+            Self::Balanced(balanced) => write!(f, "{balanced}"),
+            Self::Burst(burst) => write!(f, "burst = {burst}"),
+            Self::Complement => write!(f, "complement"),
+            Self::CheckDigit(check_digit) => write!(f, "{check_digit}"),
+            Self::Chunked(chunked) => write!(f, "{chunked}"),
+            Self::ParityBit(parity_bit) => write!(f, "{parity_bit}"),
+            Self::Repeated(_) => write!(f, "maximize_distance"),
+            Self::Correcting(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+/// A code whose discriminants are far enough apart that errors can be corrected, not only
+/// detected
+#[derive(Clone, Debug, FromMeta)]
+enum Correcting {
+    /// the variant index, with extended Hamming check bits
+    Secded,
+    /// the variant index, with BCH check bits that correct this many flipped bits
+    Bch(Bch),
+    /// every parity equation of a parity-check matrix holds
+    CheckMatrix(CheckMatrix),
+    /// the variant index, with Reed-Solomon check symbols, set from `symbol_distance`
+    #[darling(skip)]
+    ReedSolomon(ReedSolomon),
+}
+
+impl Correcting {
+    /// the arguments that each parse to a correcting code
+    const NAMES: &[&str] = &["secded", "bch", "check_matrix"];
+
+    /// Like [`Parity::encode`]
+    fn encode(&self, cursor: u128, bits: u32) -> Option<u128> {
+        match self {
+            Self::Secded => secded::encode(cursor, bits),
+            Self::Bch(bch) => bch.encode(cursor, bits),
+            Self::CheckMatrix(_) => Some(cursor),
+            Self::ReedSolomon(rs) => rs.encode(cursor, bits),
+        }
+    }
+
+    /// Like [`Parity::decode`]
+    fn decode(&self, val: u128, bits: u32) -> Option<u128> {
+        match self {
+            Self::Secded => secded::decode(val, bits),
+            Self::Bch(bch) => bch.decode(val, bits),
+            Self::CheckMatrix(matrix) => matrix.decode(val),
+            Self::ReedSolomon(rs) => rs.decode(val, bits),
+        }
+    }
+
+    /// The number of codewords that fit in `bits` bits
+    fn capacity(&self, bits: u32) -> u128 {
+        match self {
+            Self::Secded => secded::capacity(bits),
+            Self::Bch(bch) => bch.capacity(bits),
+            Self::CheckMatrix(matrix) => matrix.capacity(bits),
+            Self::ReedSolomon(rs) => rs.capacity(bits),
+        }
+    }
+}
+
+impl Display for Correcting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Secded => write!(f, "secded"),
+            Self::Bch(bch) => write!(f, "{bch}"),
+            Self::CheckMatrix(matrix) => write!(f, "{matrix}"),
+            Self::ReedSolomon(rs) => write!(f, "{rs}"),
+        }
+    }
+}
+
+/// A parity that may be left out, e.g. when only `min_distance` is given, and the span of the
+/// argument it was parsed from
+#[derive(Clone, Debug)]
+struct OptionalParity(Option<Parity>, Span);
+
+impl FromMeta for OptionalParity {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        let correcting = |item: &darling::ast::NestedMeta| match item {
+            darling::ast::NestedMeta::Meta(meta) => Correcting::NAMES
+                .iter()
+                .any(|name| meta.path().is_ident(name)),
+            darling::ast::NestedMeta::Lit(_) => false,
+        };
+        let span = items.first().map_or_else(Span::call_site, Spanned::span);
+        match items {
+            [] => Ok(Self(None, span)),
+            [item] if correcting(item) => {
+                Correcting::from_list(items).map(|code| Self(Some(Parity::Correcting(code)), span))
+            }
+            _ => Parity::from_list(items).map(|parity| Self(Some(parity), span)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromMeta)]
+struct BitParityArgs {
+    #[darling(flatten)]
+    parity: OptionalParity,
+    #[darling(default)]
+    allow_explicit_overrides: bool,
+    min_distance: Option<SpannedValue<u32>>,
+    exclude_stuck: Option<bool>,
+    granularity: Option<SpannedValue<Granularity>>,
+    layout: Option<SpannedValue<Layout>>,
+    maximize_distance: darling::util::Flag,
+    assign: Option<SpannedValue<Assign>>,
+    key: Option<SpannedValue<u64>>,
+    tag: Option<SpannedValue<u128>>,
+    tag_bits: Option<SpannedValue<u32>>,
+    symbol_distance: Option<SpannedValue<u32>>,
+    symbol_bits: Option<SpannedValue<u32>>,
+}
+
+/// Every argument of `bit_parity`, in alphabetical order
+///
+/// darling only lists the available values of an unknown argument when there are fewer than 10,
+/// so unknown arguments are caught against this first. The tests check it against the fields of
+/// [`BitParityArgs`] and the variants of [`Parity`] and [`Correcting`].
+const ARGS: &[&str] = &[
+    "allow_explicit_overrides",
+    "assign",
+    "balanced",
+    "bch",
+    "berger",
+    "burst",
+    "check_digit",
+    "check_matrix",
+    "complement",
+    "crc",
+    "even",
+    "exclude_stuck",
+    "granularity",
+    "gray",
+    "key",
+    "layout",
+    "maximize_distance",
+    "min_distance",
+    "odd",
+    "secded",
+    "symbol_bits",
+    "symbol_distance",
+    "tag",
+    "tag_bits",
+    "weight",
+];
+
+impl syn::parse::Parse for BitParityArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let items = darling::ast::NestedMeta::parse_meta_list(input.parse()?)?;
+
+        let unknown = items.iter().find_map(|item| match item {
+            darling::ast::NestedMeta::Meta(meta) => meta
+                .path()
+                .get_ident()
+                .filter(|ident| !ARGS.iter().any(|arg| ident == arg)),
+            darling::ast::NestedMeta::Lit(_) => None,
+        });
+        if let Some(ident) = unknown {
+            let available = ARGS.iter().map(|arg| format!("`{arg}`")).join(", ");
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("Unknown field: `{ident}`. Available values: {available}"),
+            ));
+        }
+
+        Ok(Self::from_list(&items)?)
+    }
+}
+
+#[derive(Clone)]
+struct Ctx {
+    repr: IntRepr,
+    parity: Option<Parity>,
+    /// the span of the parity argument, or the whole attribute if there is none
+    parity_span: Span,
+    allow_explicit_overrides: bool,
+    min_distance: Option<u32>,
+    exclude_stuck: bool,
+    maximize_distance: bool,
+    /// the key to hash variant names with, if `assign = "name_hash"`
+    name_hash_key: Option<u64>,
+    /// the number of high bits, and the tag that every discriminant has in them
+    tag: Option<(u32, u128)>,
+}
+
+impl Ctx {
+    /// A fresh iterator over every discriminant value this enum may be assigned
+    fn values<N>(&self) -> BitParityIter<N>
+    where
+        N: IntegerParity,
+    {
+        BitParityIter::new(self.parity.clone(), self.min_distance)
+            .excluding_stuck(self.exclude_stuck)
+            .tagged(self.tag)
+    }
+}
+
+fn parse_discriminant<N>(ctx: &Ctx, (_eq_tok, expr): (syn::token::Eq, Expr)) -> syn::Result<N>
+where
+    N: IntegerParity + darling::ToTokens + FromStr,
+    N::Err: Display,
+{
+    let Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit),
+        ..
+    }) = expr.clone()
+    else {
+        // the expression was not a valid literal
+        return Err(syn::Error::new(
+            expr.span(),
+            "Invalid or unsupported enum discriminant value. Only literals are allowed",
+        ));
+    };
+
+    let lit = lit.base10_parse::<N>()?;
+
+    // overriding the parity is allowed, but a stuck bus still can't be told apart from a variant
+    if ctx.exclude_stuck && lit.is_stuck() {
+        let state = if lit.to_bits() == 0 { "cleared" } else { "set" };
+        return Err(syn::Error::new(
+            expr.span(),
+            format!(
+                "explicit discriminant has every bit {state}, which is excluded by `exclude_stuck`"
+            ),
+        ));
+    }
+
+    // overriding the parity is allowed, but another enum's tag is never accepted
+    if let Some((tag_bits, tag)) = ctx.tag {
+        if lit.to_bits() >> (N::BITS - tag_bits) != tag {
+            return Err(syn::Error::new(
+                expr.span(),
+                format!(
+                    "explicit discriminant does not have the tag {tag:#b} in its high {tag_bits} bits"
+                ),
+            ));
+        }
+    }
+
+    match &ctx.parity {
+        Some(parity) if !lit.has_parity(parity) && !ctx.allow_explicit_overrides => {
+            let msg = match parity {
+                Parity::Weight(weight) => {
+                    format!("explicit discriminant does not have exactly {weight} bits set")
+                }
+                Parity::Even | Parity::Odd => {
+                    format!("explicit discriminant does not have `{parity}` parity")
+                }
+                Parity::Berger
+                | Parity::Balanced(_)
+                | Parity::Repeated(_)
+                | Parity::Correcting(
+                    Correcting::Secded | Correcting::Bch(_) | Correcting::ReedSolomon(_),
+                ) => {
+                    format!("explicit discriminant is not a valid `{parity}` codeword")
+                }
+                Parity::Crc(_) | Parity::Burst(_) => {
+                    format!("explicit discriminant does not match its `{parity}` checksum")
+                }
+                Parity::Gray(_) => {
+                    format!("explicit discriminant is skipped by the `{parity}` code")
+                }
+                Parity::CheckDigit(check_digit) => format!(
+                    "explicit discriminant does not end in a valid {} check digit",
+                    check_digit.name()
+                ),
+                Parity::Correcting(Correcting::CheckMatrix(matrix)) => {
+                    let row = matrix.failing_row(lit.to_bits()).unwrap_or_default();
+                    format!(
+                        "explicit discriminant does not have a zero syndrome under `check_matrix`, row {row} has odd parity"
+                    )
+                }
+                Parity::Complement => {
+                    "the high half of the explicit discriminant is not the complement of its low half"
+                        .to_string()
+                }
+                Parity::Chunked(chunked) => {
+                    let chunk = chunked
+                        .failing_chunk(lit.to_bits(), N::BITS)
+                        .unwrap_or_default();
+                    format!(
+                        "explicit discriminant does not have `{}` parity in {} {chunk}",
+                        chunked.parity_name(),
+                        chunked.granularity(),
+                    )
+                }
+                Parity::ParityBit(parity_bit) => {
+                    let index = parity_bit.index(lit.to_bits(), N::BITS);
+                    let state = if parity_bit.parity_bit(index) == 1 {
+                        "set"
+                    } else {
+                        "cleared"
+                    };
+                    format!(
+                        "explicit discriminant does not match the `{}` layout, bit {} should be {state}",
+                        parity_bit.layout(),
+                        parity_bit.position(N::BITS),
+                    )
+                }
+            };
+            Err(syn::Error::new(expr.span(), msg))
+        }
+        _ => Ok(lit),
+    }
+}
+
+/// Checks that every explicit discriminant is at least `min_distance` bits away from the explicit
+/// discriminants before it
+fn check_explicit_distances<N>(ctx: &Ctx, explicit_discriminants: &[(N, Span)]) -> syn::Result<()>
+where
+    N: IntegerParity,
+{
+    let Some(min_distance) = ctx.min_distance else {
+        return Ok(());
+    };
+    if ctx.allow_explicit_overrides {
+        return Ok(());
+    }
+
+    for (idx, &(val, span)) in explicit_discriminants.iter().enumerate() {
+        let close = explicit_discriminants[..idx]
+            .iter()
+            .find(|(prev, _)| prev.distance(val) < min_distance);
+
+        if let Some(&(_, prev_span)) = close {
+            let mut err = syn::Error::new(prev_span, "previous assignment here");
+
+            err.combine(syn::Error::new(
+                span,
+                format!(
+                    "explicit discriminant is fewer than {min_distance} bits away from a previous discriminant"
+                ),
+            ));
+
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that no burst of up to `burst` bits turns one variant into another
+///
+/// Assigned discriminants hold this by construction, but explicit overrides are only allowed as
+/// long as they keep it.
+fn check_burst_detection<N>(ctx: &Ctx, discriminants: &[(N, Span)]) -> syn::Result<()>
+where
+    N: IntegerParity,
+{
+    let Some(Parity::Burst(burst)) = ctx.parity else {
+        return Ok(());
+    };
+
+    for (idx, &(val, span)) in discriminants.iter().enumerate() {
+        let close = discriminants[..idx]
+            .iter()
+            .map(|&(prev, prev_span)| (burst::length(prev.to_bits(), val.to_bits()), prev_span))
+            .find(|&(length, _)| length <= burst);
+
+        if let Some((length, prev_span)) = close {
+            let mut err = syn::Error::new(prev_span, "previous assignment here");
+
+            err.combine(syn::Error::new(
+                span,
+                format!(
+                    "discriminant differs from a previous discriminant by a burst of {length} bits, which `{}` would not detect",
+                    Parity::Burst(burst)
+                ),
+            ));
+
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that every pair of discriminants is far enough apart for `correct` to recover every
+/// error it promises to
+///
+/// Assigned discriminants hold this by construction, but explicit overrides are only allowed as
+/// long as they keep it.
+fn check_correction_distance<N>(ctx: &Ctx, discriminants: &[(N, Span)]) -> syn::Result<()>
+where
+    N: IntegerParity,
+{
+    // the distance needed, the number of errors corrected, and what they are counted in
+    let Some(Parity::Correcting(code)) = &ctx.parity else {
+        return Ok(());
+    };
+    let (needed, radius, unit, error) = match code {
+        // one flipped bit is corrected, and two are still told apart from it
+        Correcting::Secded => (4, 1, "bits", "flipped bit"),
+        Correcting::Bch(bch) => (2 * bch.t() + 1, bch.t(), "bits", "flipped bit"),
+        Correcting::ReedSolomon(rs) => (rs.distance(), rs.radius(), "symbols", "corrupted symbol"),
+        // the code is whatever the matrix makes it, so there is no distance to keep
+        Correcting::CheckMatrix(_) => return Ok(()),
+    };
+    let distance = |a: N, b: N| match code {
+        Correcting::ReedSolomon(rs) => rs.symbol_distance(a.to_bits(), b.to_bits()),
+        _ => a.distance(b),
+    };
+
+    for (idx, &(val, span)) in discriminants.iter().enumerate() {
+        let close = discriminants[..idx]
+            .iter()
+            .find(|&&(prev, _)| distance(prev, val) < needed);
+
+        if let Some(&(_, prev_span)) = close {
+            let mut err = syn::Error::new(prev_span, "previous assignment here");
+
+            err.combine(syn::Error::new(
+                span,
+                format!(
+                    "discriminant is fewer than {needed} {unit} away from a previous discriminant, so `{code}` could not correct {radius} {error}{}",
+                    if radius == 1 { "" } else { "s" }
+                ),
+            ));
+
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that neighbouring variants of a Gray code differ in exactly one bit
+fn check_gray_adjacency<N>(ctx: &Ctx, discriminants: &[(N, Span)]) -> syn::Result<()>
+where
+    N: IntegerParity,
+{
+    let Some(Parity::Gray(gray)) = ctx.parity else {
+        return Ok(());
+    };
+    if ctx.allow_explicit_overrides {
+        return Ok(());
+    }
+
+    let seam = discriminants
+        .last()
+        .zip(discriminants.first())
+        .filter(|_| gray.wraps());
+    for ((prev, prev_span), (val, span)) in discriminants.iter().tuple_windows().chain(seam) {
+        if prev.distance(*val) != 1 {
+            let mut err = syn::Error::new(*prev_span, "previous assignment here");
+
+            err.combine(syn::Error::new(
+                *span,
+                format!("discriminant must differ from the previous variant in exactly one bit for `{gray}`"),
+            ));
+
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+fn next_discriminant<N>(
+    ctx: &Ctx,
+    bpi: &mut BitParityIter<N>,
+    variant: &Variant,
+    explicit_discriminants: &HashMap<N, Span>,
+    near: &[(N, &Constraint, &Ident)],
+) -> syn::Result<N>
+where
+    N: IntegerParity + Eq + std::hash::Hash,
+{
+    let constraints = near
+        .iter()
+        .map(|&(other, constraint, _)| (other, constraint.distance))
+        .collect_vec();
+    // TODO not a huge fan of the control flow in this function...
+    if let Some(next_val) = bpi.next_constrained(&constraints) {
+        if let Some(span) = explicit_discriminants.get(&next_val) {
+            let mut err = syn::Error::new(*span, "previous assignment here");
+
+            err.combine(syn::Error::new(
+                variant.span(),
+                "discriminant value is already assigned",
+            ));
+
+            return Err(err);
+        }
+        return Ok(next_val);
+    }
+
+    if bpi.gave_up() {
+        let shrink = if near.is_empty() {
+            "a smaller `min_distance`"
+        } else {
+            "smaller distances"
+        };
+        return Err(syn::Error::new_spanned(
+            variant,
+            format!(
+                "gave up searching for a discriminant value for `{}` repr type, {shrink} may fit",
+                ctx.repr
+            ),
+        ));
+    }
+
+    if let (Some(stuck), Some(parity)) = (bpi.stuck(), &ctx.parity) {
+        let state = if stuck.to_bits() == 0 {
+            "cleared"
+        } else {
+            "set"
+        };
+        return Err(syn::Error::new_spanned(
+            variant,
+            format!(
+                "`{parity}` assigns `{}` a discriminant with every bit {state}, which is excluded by `exclude_stuck`",
+                variant.ident
+            ),
+        ));
+    }
+
+    // if we got out of the for loop without returning, then we ran out of discriminants
+    let msg = if !near.is_empty() {
+        // the values are assigned greedily in order, so another assignment of the earlier
+        // variants may still meet the constraints
+        let away = near
+            .iter()
+            .map(|(_, constraint, other)| {
+                format!("at least {} bits away from `{other}`", constraint.distance)
+            })
+            .join(", and ");
+        format!(
+            "the greedy assignment could not keep `{}` {away} with the remaining `{}` values, an explicit discriminant may still fit",
+            variant.ident, ctx.repr
+        )
+    } else if let Some((tag_bits, tag)) = ctx.tag {
+        format!(
+            "ran out of discriminant values for `{}` repr type with the tag {tag:#b} in the high {tag_bits} bits",
+            ctx.repr
+        )
+    } else if let Some(min_distance) = ctx.min_distance {
+        match ctx.values::<N>().remaining(false) {
+            Remaining::Exact(capacity) => format!(
+                "ran out of discriminant values for `{}` repr type, a minimum distance of {min_distance} fits at most {capacity} variants",
+                ctx.repr
+            ),
+            Remaining::AtLeast(_) => format!(
+                "ran out of discriminant values for `{}` repr type with a minimum distance of {min_distance}",
+                ctx.repr
+            ),
+        }
+    } else if let Some(Parity::Chunked(chunked)) = ctx.parity {
+        // the index is spread from the lowest chunk up, so the highest one runs out first
+        let capacity =
+            chunked.capacity(N::BITS) - stuck_codewords::<N>(ctx, &Parity::Chunked(chunked));
+        format!(
+            "ran out of discriminant values for `{}` repr type, {} {} has no `{}` values left, `{chunked}` fits at most {capacity} variants",
+            ctx.repr,
+            chunked.granularity(),
+            chunked.chunks(N::BITS) - 1,
+            chunked.parity_name(),
+        )
+    } else if let Some((parity, capacity)) = ctx
+        .parity
+        .as_ref()
+        .and_then(|parity| Some((parity, parity.capacity(N::BITS)?)))
+        .map(|(parity, capacity)| (parity, capacity - stuck_codewords::<N>(ctx, parity)))
+    {
+        format!(
+            "ran out of discriminant values for `{}` repr type, `{parity}` fits at most {capacity} variants",
+            ctx.repr,
+        )
+    } else {
+        format!(
+            "ran out of discriminant values for `{}` repr type",
+            ctx.repr
+        )
+    };
+    Err(syn::Error::new_spanned(variant, msg))
+}
+
+/// The number of codewords of `parity` that are skipped by `exclude_stuck`
+fn stuck_codewords<N>(ctx: &Ctx, parity: &Parity) -> u128
+where
+    N: IntegerParity,
+{
+    if !ctx.exclude_stuck {
+        return 0;
+    }
+    let ones = u128::MAX >> (u128::BITS - N::BITS);
+    [0, ones]
+        .into_iter()
+        .filter(|&val| parity.decode(val, N::BITS).is_some())
+        .count() as u128
+}
+
+/// The value of every variant, and the iterator that assigned them, which counts how many more fit
+type Assignment<T> = (Vec<(T, Span)>, BitParityIter<T>);
+
+/// Assigns a discriminant to every variant, given the parsed explicit discriminants
+fn assign<T>(
+    ctx: &Ctx,
+    enum_item: &ItemEnum,
+    explicit: &[Option<T>],
+    constraints: &[Constraint],
+) -> syn::Result<Assignment<T>>
+where
+    T: IntegerParity + Eq + std::hash::Hash + Ord,
+{
+    if let Some(min_distance) = ctx.min_distance {
+        let fits = plotkin_bound(min_distance, T::BITS);
+        let over = enum_item.variants.iter().nth(fits);
+        if let Some(variant) = over.filter(|_| ctx.tag.is_none() && !ctx.allow_explicit_overrides) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!(
+                    "ran out of discriminant values for `{}` repr type, a minimum distance of {min_distance} fits at most {fits} variants",
+                    ctx.repr
+                ),
+            ));
+        }
+    }
+
+    let mut bpi = ctx.values::<T>();
+    for &val in explicit.iter().flatten() {
+        bpi.reserve(val);
+    }
+    let mut assigned = enum_item
+        .variants
+        .iter()
+        .zip(explicit)
+        .filter_map(|(variant, val)| Some(((*val)?, variant.span())))
+        .collect::<HashMap<T, Span>>();
+    let mut values: Vec<(T, Span)> = Vec::with_capacity(enum_item.variants.len());
+    for (index, (variant, &explicit_disc)) in enum_item.variants.iter().zip(explicit).enumerate() {
+        // the variants this one is constrained against, that already have a value
+        let near = constraints
+            .iter()
+            .filter_map(|constraint| {
+                let other = constraint.partner(index)?;
+                let other_val = if other < index {
+                    values[other].0
+                } else if explicit_disc.is_none() {
+                    // a later explicit discriminant is already known, so keep away from it now
+                    explicit[other]?
+                } else {
+                    // two explicit discriminants are checked once both are reached
+                    return None;
+                };
+                Some((other_val, constraint, &enum_item.variants[other].ident))
+            })
+            .collect_vec();
+
+        let next_disc = if let Some(next_disc) = explicit_disc {
+            let close = near
+                .iter()
+                .find(|(other, constraint, _)| other.distance(next_disc) < constraint.distance);
+            if let Some((_, constraint, other)) = close {
+                return Err(syn::Error::new(
+                    variant.span(),
+                    format!(
+                        "explicit discriminant is fewer than {} bits away from `{other}`",
+                        constraint.distance
+                    ),
+                ));
+            }
+            bpi.set_override(next_disc);
+
+            next_disc
+        } else {
+            let next_disc = next_discriminant(ctx, &mut bpi, variant, &assigned, &near)?;
+
+            assigned.insert(next_disc, variant.span());
+            next_disc
+        };
+
+        values.push((next_disc, variant.span()));
+    }
+
+    Ok((values, bpi))
+}
+
+/// The smallest number of bits that any two values differ in, or `N::BITS` if there are fewer
+/// than two
+fn min_pairwise_distance<N>(values: &[(N, Span)]) -> u32
+where
+    N: IntegerParity,
+{
+    values
+        .iter()
+        .tuple_combinations()
+        .map(|(&(a, _), &(b, _))| a.distance(b))
+        .min()
+        .unwrap_or(N::BITS)
+}
+
+/// Whether the Plotkin bound allows `len` values in `bits` bits to all differ in at least
+/// `distance` bits
+const fn plotkin_allows(len: usize, distance: u32, bits: u32) -> bool {
+    len <= plotkin_bound(distance, bits)
+}
+
+/// The most values in `bits` bits that can all differ in at least `distance` bits, by the Plotkin
+/// bound, or `usize::MAX` if the bound does not apply
+const fn plotkin_bound(distance: u32, bits: u32) -> usize {
+    if 2 * distance <= bits {
+        return usize::MAX;
+    }
+    match 2 * (distance / (2 * distance - bits)) as usize {
+        // a single value has no other to differ from
+        0 => 1,
+        bound => bound,
+    }
+}
+
+/// Picks every discriminant from a keyed hash of its variant name
+///
+/// Variants are hashed in order of their names, after every explicit discriminant is reserved, so
+/// reordering the variants keeps every discriminant.
+fn assign_by_name<T>(
+    ctx: &Ctx,
+    enum_item: &ItemEnum,
+    explicit: &[Option<T>],
+    constraints: &[Constraint],
+    key: u64,
+) -> syn::Result<Vec<(T, Span)>>
+where
+    T: IntegerParity + Eq,
+{
+    let variants = &enum_item.variants;
+    // explicit discriminants are never hashed, so check their constraints up front
+    for constraint in constraints {
+        let (a, b) = constraint.pair();
+        let (Some(a_val), Some(b_val)) = (explicit[a], explicit[b]) else {
+            continue;
+        };
+        if a_val.distance(b_val) < constraint.distance {
+            let (later, earlier) = if a < b { (b, a) } else { (a, b) };
+            return Err(syn::Error::new(
+                variants[later].span(),
+                format!(
+                    "explicit discriminant is fewer than {} bits away from `{}`",
+                    constraint.distance, variants[earlier].ident
+                ),
+            ));
+        }
+    }
+
+    let mut values = explicit.to_vec();
+    let order = (0..variants.len())
+        .filter(|&index| explicit[index].is_none())
+        .sorted_by_cached_key(|&index| variants[index].ident.to_string());
+    for index in order {
+        let name = variants[index].ident.to_string();
+        let accepts = |val: T| {
+            let far_from_all = values.iter().flatten().all(|&other| {
+                other != val && ctx.min_distance.is_none_or(|d| other.distance(val) >= d)
+            });
+            let far_from_partners = constraints.iter().all(|constraint| {
+                constraint
+                    .partner(index)
+                    .and_then(|other| values[other])
+                    .is_none_or(|other| other.distance(val) >= constraint.distance)
+            });
+            !(ctx.exclude_stuck && val.is_stuck()) && far_from_all && far_from_partners
+        };
+        let value = (0..name_hash::MAX_ATTEMPTS)
+            .map(|attempt| T::from_bits(name_hash::hash(key, &name, attempt)))
+            .map(|val| match &ctx.parity {
+                // only `even` and `odd` are allowed, so flipping one bit fixes the parity
+                Some(parity) if !val.has_parity(parity) => T::from_bits(val.to_bits() ^ 1),
+                _ => val,
+            })
+            .find(|&val| accepts(val));
+        let Some(value) = value else {
+            return Err(syn::Error::new(
+                variants[index].span(),
+                format!(
+                    "ran out of discriminant values for `{}` repr type, no hash of `{name}` fits in {} attempts",
+                    ctx.repr,
+                    name_hash::MAX_ATTEMPTS
+                ),
+            ));
+        };
+        values[index] = Some(value);
+    }
+
+    Ok(values
+        .into_iter()
+        .flatten()
+        .zip(variants)
+        .map(|(val, variant)| (val, variant.span()))
+        .collect())
+}
+
+/// Assigns the optimal code of [`plotkin`], if there are few enough variants, none of them with an
+/// explicit discriminant, and it keeps every constraint
+///
+/// The code is moved off the stuck values by XOR-ing it with the smallest mask that does. Each
+/// value rules out two masks, so one of the first `2 * len + 1` does.
+fn plotkin_code<T>(
+    ctx: &Ctx,
+    enum_item: &ItemEnum,
+    explicit: &[Option<T>],
+    constraints: &[Constraint],
+) -> Option<Assignment<T>>
+where
+    T: IntegerParity,
+{
+    if explicit.iter().any(Option::is_some) {
+        return None;
+    }
+    let len = enum_item.variants.len();
+    let code = plotkin::code(len, T::BITS)?;
+    let values = (0..=2 * len as u128)
+        .map(|mask| {
+            code.iter()
+                .map(|&val| T::from_bits(val ^ mask))
+                .collect_vec()
+        })
+        .find(|values| !ctx.exclude_stuck || !values.iter().any(|val| val.is_stuck()))?;
+    let kept = constraints.iter().all(|constraint| {
+        let (a, b) = constraint.pair();
+        values[a].distance(values[b]) >= constraint.distance
+    });
+    if !kept {
+        return None;
+    }
+
+    // any value that is far enough from all of them could still be added
+    let mut bpi = BitParityIter::new(None, plotkin::distance(len, T::BITS))
+        .excluding_stuck(ctx.exclude_stuck);
+    for &val in &values {
+        bpi.reserve(val);
+    }
+    let values = values
+        .into_iter()
+        .zip(&enum_item.variants)
+        .map(|(val, variant)| (val, variant.span()))
+        .collect();
+    Some((values, bpi))
+}
+
+/// Searches for an assignment with the largest minimum distance
+///
+/// Up to four variants get the optimal code of [`plotkin`], when it fits. Otherwise each
+/// candidate is a greedy `min_distance` assignment of values with at most [`repeated::MAX_WIDTH`]
+/// bits, repeated to fill the repr. Every number of copies, and every distance from the largest
+/// down, is tried in order, so the search is deterministic, but may fall short of the optimum.
+fn maximize_distance<T>(
+    ctx: &Ctx,
+    enum_item: &ItemEnum,
+    explicit: &[Option<T>],
+    constraints: &[Constraint],
+) -> syn::Result<Assignment<T>>
+where
+    T: IntegerParity + Eq + std::hash::Hash + Ord,
+{
+    if let Some(assigned) = plotkin_code(ctx, enum_item, explicit, constraints) {
+        return Ok(assigned);
+    }
+
+    let len = enum_item.variants.len();
+    let mut best: Option<(u32, Assignment<T>)> = None;
+    for copies in repeated::copies(T::BITS) {
+        let width = T::BITS / copies;
+        for distance in (1..=width).rev() {
+            let target = distance * copies;
+            if best.as_ref().is_some_and(|(best, _)| *best >= target) {
+                break;
+            }
+            if !plotkin_allows(len, distance, width) {
+                continue;
+            }
+
+            let candidate = Ctx {
+                parity: Some(Parity::Repeated(copies)),
+                min_distance: Some(target),
+                ..*ctx
+            };
+            if let Ok(assigned) = assign(&candidate, enum_item, explicit, constraints) {
+                let achieved = min_pairwise_distance(&assigned.0);
+                if best.as_ref().is_none_or(|(best, _)| achieved > *best) {
+                    best = Some((achieved, assigned));
+                }
+                break;
+            }
+        }
+    }
+
+    match best {
+        Some((_, assigned)) => Ok(assigned),
+        // nothing fits, so report the error without any distance
+        None => assign(ctx, enum_item, explicit, constraints),
+    }
+}
+
+/// Assigns a discriminant to every variant in the way that `ctx` asks for, and counts how many more
+/// variants would fit
+fn assign_values<T>(
+    ctx: &Ctx,
+    enum_item: &ItemEnum,
+    explicit: &[Option<T>],
+    constraints: &[Constraint],
+) -> syn::Result<(Vec<(T, Span)>, Remaining)>
+where
+    T: IntegerParity + Eq + std::hash::Hash + Ord,
+{
+    if ctx.maximize_distance {
+        let (values, bpi) = maximize_distance(ctx, enum_item, explicit, constraints)?;
+        Ok((values, bpi.remaining(false)))
+    } else if let Some(key) = ctx.name_hash_key {
+        let values = assign_by_name(ctx, enum_item, explicit, constraints, key)?;
+        // a hashed variant may take any value that is still free
+        let mut bpi = ctx.values::<T>();
+        for &(val, _) in &values {
+            bpi.reserve(val);
+        }
+        Ok((values, bpi.remaining(true)))
+    } else {
+        let (values, bpi) = assign(ctx, enum_item, explicit, constraints)?;
+        Ok((values, bpi.remaining(false)))
+    }
+}
+
+/// Checks the arguments that depend on the number of bits in the repr
+fn check_repr_width<N>(ctx: &Ctx) -> syn::Result<()>
+where
+    N: IntegerParity,
+{
+    if matches!(ctx.parity, Some(Parity::Complement)) && N::BITS < 16 {
+        return Err(syn::Error::new(
+            ctx.parity_span,
+            format!(
+                "`complement` needs a repr of at least 16 bits, found `{}`",
+                ctx.repr
+            ),
+        ));
+    }
+
+    // the index needs at least one bit, or no variant would fit
+    if let Some(Parity::Crc(crc)) = ctx.parity {
+        if crc.width() >= N::BITS {
+            return Err(syn::Error::new(
+                ctx.parity_span,
+                format!(
+                    "CRC `width` must be fewer than the {} bits of `{}`, found {}",
+                    N::BITS,
+                    ctx.repr,
+                    crc.width()
+                ),
+            ));
+        }
+    }
+
+    if let Some((tag_bits, _)) = ctx.tag.filter(|&(tag_bits, _)| tag_bits >= N::BITS) {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "`tag_bits` must be fewer than the {} bits of `{}`, found {tag_bits}",
+                N::BITS,
+                ctx.repr
+            ),
+        ));
+    }
+
+    if let Some(Parity::Correcting(Correcting::CheckMatrix(matrix))) = &ctx.parity {
+        let wide = matrix
+            .rows()
+            .iter()
+            .position(|row| row.checked_shr(N::BITS).unwrap_or(0) != 0);
+        if let Some(row) = wide {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "`check_matrix` row {row} has bits above the {} bits of `{}`",
+                    N::BITS,
+                    ctx.repr
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn generic_expand<T>(ctx: &Ctx, mut enum_item: ItemEnum) -> syn::Result<TokenStream>
+where
+    T: IntegerParity + darling::ToTokens + FromStr + Eq + std::hash::Hash + std::fmt::Debug + Ord,
+    T::Err: Display,
+{
+    check_repr_width::<T>(ctx)?;
+
+    let constraints = constraint::take_constraints(&mut enum_item)?;
+    if let Some(constraint) = constraints.iter().find(|c| c.distance > T::BITS) {
+        return Err(syn::Error::new(
+            constraint.span,
+            format!(
+                "a `{}` repr can only be up to {} bits away, found {}",
+                ctx.repr,
+                T::BITS,
+                constraint.distance
+            ),
+        ));
+    }
+
+    // iterate through all the enum variants, and validate all the explicit discriminants
+    let explicit = enum_item
+        .variants
+        .iter()
+        .map(|variant| {
+            variant
+                .discriminant
+                .clone()
+                .map(|disc| parse_discriminant::<T>(ctx, disc))
+                .transpose()
+        })
+        .collect::<syn::Result<Vec<Option<T>>>>()?;
+    let explicit_discriminants = enum_item
+        .variants
+        .iter()
+        .zip(&explicit)
+        .filter_map(|(variant, val)| Some(((*val)?, variant.span())))
+        .collect_vec();
+    check_explicit_distances(ctx, &explicit_discriminants)?;
+
+    let (values, remaining) = assign_values(ctx, &enum_item, &explicit, &constraints)?;
+    check_gray_adjacency(ctx, &values)?;
+    check_burst_detection(ctx, &values)?;
+    check_correction_distance(ctx, &values)?;
+
+    let mut discriminants = Vec::with_capacity(values.len());
+    for (variant, &(value, _)) in enum_item.variants.iter_mut().zip(&values) {
+        variant.discriminant = Some((syn::token::Eq::default(), syn::parse_quote!(#value)));
+        discriminants.push(quote!(#value));
+    }
+
+    let mut items = TokenStream::new();
+    if ctx.maximize_distance {
+        items.extend(generate::min_distance(
+            &enum_item,
+            MinDistance::Exact(min_pairwise_distance(&values)),
+        ));
+    }
+    if let Some(Parity::Correcting(Correcting::CheckMatrix(matrix))) = &ctx.parity {
+        items.extend(generate::min_distance(
+            &enum_item,
+            matrix.min_distance(T::BITS),
+        ));
+    }
+    items.extend(generate::remaining_capacity(&enum_item, remaining));
+    let codebook = Codebook::new(&enum_item, ctx.repr, discriminants);
+    if let Some(codebook) = &codebook {
+        items.extend(codebook.repr_consts());
+        items.extend(codebook.decode_nearest());
+        items.extend(codebook.decode_with_erasures());
+        items.extend(codebook.decode_soft());
+    }
+    let methods = match (&ctx.parity, &codebook) {
+        (Some(Parity::Correcting(Correcting::Secded)), Some(codebook)) => codebook.correct(1),
+        (Some(Parity::Correcting(Correcting::Bch(bch))), Some(codebook)) => {
+            codebook.correct(bch.t())
+        }
+        (Some(Parity::Correcting(Correcting::ReedSolomon(rs))), Some(codebook)) => {
+            codebook.correct_symbols(rs.radius(), rs.symbol_bits(), T::BITS)
+        }
+        (Some(Parity::CheckDigit(check_digit)), Some(codebook)) => {
+            codebook.decimal_parser(check_digit.name(), &check_digit.validate())
+        }
+        _ => TokenStream::new(),
+    };
+    items.extend(methods);
+    if let (Some((tag_bits, tag)), Some(codebook)) = (ctx.tag, &codebook) {
+        let shift = T::BITS - tag_bits;
+        let mask = T::from_bits(((1 << tag_bits) - 1) << shift);
+        let tag = T::from_bits(tag << shift);
+        items.extend(codebook.decode_tagged(&quote!(#mask), &quote!(#tag)));
+    }
+    let impl_block = if items.is_empty() {
+        TokenStream::new()
+    } else {
+        impl_block(&enum_item, &items)
+    };
+    let try_from = codebook.map(|codebook| {
+        codebook.try_from_impl(&ParityCheck::new(ctx.parity.as_ref(), T::BITS).expr::<T>())
+    });
+
+    Ok(quote! {#enum_item #impl_block #try_from})
+}
+fn specialize_expand(ctx: &Ctx, enum_item: ItemEnum) -> syn::Result<TokenStream> {
+    match ctx.repr {
+        IntRepr::U8 => generic_expand::<u8>(ctx, enum_item),
+        IntRepr::U16 => generic_expand::<u16>(ctx, enum_item),
+        IntRepr::U32 => generic_expand::<u32>(ctx, enum_item),
+        IntRepr::U64 => generic_expand::<u64>(ctx, enum_item),
+        IntRepr::U128 => generic_expand::<u128>(ctx, enum_item),
+        IntRepr::Usize => generic_expand::<usize>(ctx, enum_item),
+        IntRepr::I8 => generic_expand::<i8>(ctx, enum_item),
+        IntRepr::I16 => generic_expand::<i16>(ctx, enum_item),
+        IntRepr::I32 => generic_expand::<i32>(ctx, enum_item),
+        IntRepr::I64 => generic_expand::<i64>(ctx, enum_item),
+        IntRepr::I128 => generic_expand::<i128>(ctx, enum_item),
+        IntRepr::Isize => generic_expand::<isize>(ctx, enum_item),
+    }
+}
+
+/// Checks the arguments that are invalid on their own, or in combination
+fn check_args(args: &BitParityArgs) -> syn::Result<()> {
+    if let Some(min_distance) = args.min_distance.as_ref().filter(|d| ***d == 0) {
+        return Err(syn::Error::new(
+            min_distance.span(),
+            "`min_distance` must be at least 1",
+        ));
+    }
+    if matches!(args.parity.0, Some(Parity::Burst(0))) {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`burst` must be at least 1",
+        ));
+    }
+    if args.maximize_distance.is_present()
+        && (args.parity.0.is_some()
+            || args.min_distance.is_some()
+            || args.symbol_distance.is_some())
+    {
+        return Err(syn::Error::new(
+            args.maximize_distance.span(),
+            "`maximize_distance` cannot be combined with a parity or `min_distance`",
+        ));
+    }
+    check_symbol_args(args)?;
+    if args.allow_explicit_overrides && matches!(args.parity.0, Some(Parity::CheckDigit(_))) {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "`allow_explicit_overrides` cannot be combined with `check_digit`, since `from_decimal` would reject the overrides",
+        ));
+    }
+    let name_hash = args
+        .assign
+        .as_ref()
+        .filter(|assign| ***assign == Assign::NameHash);
+    if let Some(key) = args.key.as_ref().filter(|_| name_hash.is_none()) {
+        return Err(syn::Error::new(
+            key.span(),
+            "`key` can only be used with `assign = \"name_hash\"`",
+        ));
+    }
+    if let Some(assign) = name_hash {
+        let parity_ok = matches!(args.parity.0, None | Some(Parity::Even | Parity::Odd))
+            && args
+                .granularity
+                .as_deref()
+                .is_none_or(|&granularity| granularity == Granularity::Word)
+            && args.layout.is_none()
+            && args.symbol_distance.is_none()
+            && !args.maximize_distance.is_present();
+        if !parity_ok {
+            return Err(syn::Error::new(
+                assign.span(),
+                "`assign = \"name_hash\"` can only be combined with `even`, `odd` or `min_distance`",
+            ));
+        }
+    }
+    check_tag_args(args)?;
+    if args.parity.0.is_none()
+        && args.min_distance.is_none()
+        && args.symbol_distance.is_none()
+        && !args.maximize_distance.is_present()
+    {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "expected a parity, such as `even` or `odd`, a `min_distance`, or `maximize_distance`",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that `tag` and `tag_bits` are given together, fit, and have a parity they work with
+fn check_tag_args(args: &BitParityArgs) -> syn::Result<()> {
+    let (tag, tag_bits) = match (&args.tag, &args.tag_bits) {
+        (None, None) => return Ok(()),
+        (Some(tag), Some(tag_bits)) => (tag, tag_bits),
+        (Some(tag), None) => {
+            return Err(syn::Error::new(tag.span(), "`tag` needs `tag_bits`"));
+        }
+        (None, Some(tag_bits)) => {
+            return Err(syn::Error::new(tag_bits.span(), "`tag_bits` needs `tag`"));
+        }
+    };
+    if **tag_bits == 0 {
+        return Err(syn::Error::new(
+            tag_bits.span(),
+            "`tag_bits` must be at least 1",
+        ));
+    }
+    if tag.checked_shr(**tag_bits).unwrap_or(0) != 0 {
+        return Err(syn::Error::new(
+            tag.span(),
+            format!("`tag` does not fit in {} bits", **tag_bits),
+        ));
+    }
+    // the tagged values are a contiguous range, which only parities that count through the values
+    // directly can be limited to
+    let parity_ok = matches!(
+        args.parity.0,
+        None | Some(
+            Parity::Even
+                | Parity::Odd
+                | Parity::Weight(_)
+                | Parity::Correcting(Correcting::CheckMatrix(_))
+        )
+    ) && args
+        .granularity
+        .as_deref()
+        .is_none_or(|&granularity| granularity == Granularity::Word)
+        && args.layout.is_none()
+        && args.symbol_distance.is_none()
+        && args
+            .assign
+            .as_deref()
+            .is_none_or(|&assign| assign == Assign::Sequential)
+        && !args.maximize_distance.is_present();
+    if !parity_ok {
+        return Err(syn::Error::new(
+            tag.span(),
+            "`tag` can only be combined with `even`, `odd`, `weight`, `check_matrix` or `min_distance`",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that `symbol_distance` is used on its own, and `symbol_bits` only with it
+fn check_symbol_args(args: &BitParityArgs) -> syn::Result<()> {
+    let Some(symbol_distance) = &args.symbol_distance else {
+        return match &args.symbol_bits {
+            Some(symbol_bits) => Err(syn::Error::new(
+                symbol_bits.span(),
+                "`symbol_bits` needs `symbol_distance`",
+            )),
+            None => Ok(()),
+        };
+    };
+    if **symbol_distance == 0 {
+        return Err(syn::Error::new(
+            symbol_distance.span(),
+            "`symbol_distance` must be at least 1",
+        ));
+    }
+    if args.parity.0.is_some() {
+        return Err(syn::Error::new(
+            symbol_distance.span(),
+            "`symbol_distance` is used instead of a parity, such as `even` or `odd`",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks that the discriminants do not depend on the width of a `usize` or `isize` repr
+///
+/// The macro only knows the width on the host, which the target may not share, so the arguments
+/// that place bits relative to the top of the word, or spread them over all of it, are rejected.
+fn check_pointer_width(args: &BitParityArgs, repr: IntRepr) -> syn::Result<()> {
+    if !repr.is_pointer_width() {
+        return Ok(());
+    }
+
+    let parity = args
+        .parity
+        .0
+        .as_ref()
+        .filter(|parity| {
+            matches!(
+                parity,
+                Parity::Berger | Parity::Balanced(_) | Parity::Complement | Parity::Correcting(_)
+            )
+        })
+        .map(|parity| (args.parity.1, parity.to_string()));
+    let granularity = args
+        .granularity
+        .as_ref()
+        .filter(|granularity| ***granularity != Granularity::Word)
+        .map(|granularity| (granularity.span(), "granularity".to_owned()));
+    let layout = args
+        .layout
+        .as_ref()
+        .filter(|layout| ***layout == Layout::ParityMsb)
+        .map(|layout| (layout.span(), "layout = \"parity_msb\"".to_owned()));
+    let assign = args
+        .assign
+        .as_ref()
+        .filter(|assign| ***assign == Assign::NameHash)
+        .map(|assign| (assign.span(), "assign = \"name_hash\"".to_owned()));
+    let tag = args.tag.as_ref().map(|tag| (tag.span(), "tag".to_owned()));
+    let symbol_distance = args
+        .symbol_distance
+        .as_ref()
+        .map(|symbol_distance| (symbol_distance.span(), "symbol_distance".to_owned()));
+    let maximize_distance = args.maximize_distance.is_present().then(|| {
+        (
+            args.maximize_distance.span(),
+            "maximize_distance".to_owned(),
+        )
+    });
+
+    let width_dependent = [
+        parity,
+        granularity,
+        layout,
+        assign,
+        tag,
+        symbol_distance,
+        maximize_distance,
+    ]
+    .into_iter()
+    .flatten()
+    .next();
+    match width_dependent {
+        Some((span, name)) => Err(syn::Error::new(
+            span,
+            format!(
+                "`{name}` depends on the width of the repr, which is not fixed for `{repr}`, use a fixed width repr such as `u32` instead"
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Combines the parity with the arguments that refine it
+fn resolve_parity(
+    args: &BitParityArgs,
+    enum_item: &ItemEnum,
+    repr: IntRepr,
+) -> syn::Result<Option<Parity>> {
+    if let Some(symbol_distance) = &args.symbol_distance {
+        let symbol_bits = args.symbol_bits.as_ref();
+        let code = ReedSolomon::new(**symbol_distance, symbol_bits.map_or(4, |bits| **bits));
+        return match code {
+            Some(code) => Ok(Some(Parity::Correcting(Correcting::ReedSolomon(code)))),
+            None => Err(syn::Error::new(
+                symbol_bits.map_or_else(Span::call_site, SpannedValue::span),
+                format!(
+                    "`symbol_bits` must be one of {}",
+                    ReedSolomon::symbol_sizes()
+                        .map(|bits| bits.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )),
+        };
+    }
+    let parity = match args.parity.0.clone() {
+        Some(Parity::Gray(gray)) => {
+            if let Some(min_distance) = &args.min_distance {
+                return Err(syn::Error::new(
+                    min_distance.span(),
+                    "`gray` cannot be combined with `min_distance`",
+                ));
+            }
+            let len = enum_item.variants.len();
+            if gray.wraps() && len % 2 == 1 && len > 1 {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "`gray(wrap)` needs an even number of variants",
+                ));
+            }
+            Some(Parity::Gray(gray.with_len(len as u128)))
+        }
+        Some(Parity::CheckDigit(check_digit)) => Some(Parity::CheckDigit(
+            check_digit.with_signed(repr.is_signed()),
+        )),
+        parity => parity,
+    };
+    let granularity = args
+        .granularity
+        .as_ref()
+        .filter(|granularity| ***granularity != Granularity::Word);
+    let parity = match (parity, granularity) {
+        (parity, None) => parity,
+        (Some(Parity::Even), Some(granularity)) => {
+            Chunked::new(false, **granularity).map(Parity::Chunked)
+        }
+        (Some(Parity::Odd), Some(granularity)) => {
+            Chunked::new(true, **granularity).map(Parity::Chunked)
+        }
+        (_, Some(granularity)) => {
+            return Err(syn::Error::new(
+                granularity.span(),
+                "`granularity` can only be combined with `even` or `odd`",
+            ));
+        }
+    };
+
+    match (parity, args.layout.as_ref()) {
+        (parity, None) => Ok(parity),
+        (_, Some(layout)) if granularity.is_some() => Err(syn::Error::new(
+            layout.span(),
+            "`layout` cannot be combined with `granularity`",
+        )),
+        (Some(Parity::Even), Some(layout)) => {
+            Ok(Some(Parity::ParityBit(ParityBit::new(false, **layout))))
+        }
+        (Some(Parity::Odd), Some(layout)) => {
+            Ok(Some(Parity::ParityBit(ParityBit::new(true, **layout))))
+        }
+        (_, Some(layout)) => Err(syn::Error::new(
+            layout.span(),
+            "`layout` can only be combined with `even` or `odd`",
+        )),
+    }
+}
+
+fn try_expand(args: &BitParityArgs, enum_item: ItemEnum) -> syn::Result<TokenStream> {
+    check_args(args)?;
+    let repr = IntRepr::from_attributes(&enum_item.attrs)?;
+    check_pointer_width(args, repr)?;
+    let parity = resolve_parity(args, &enum_item, repr)?;
+
+    let ctx = Ctx {
+        repr,
+        parity,
+        parity_span: args.parity.1,
+        allow_explicit_overrides: args.allow_explicit_overrides,
+        min_distance: args.min_distance.as_deref().copied(),
+        exclude_stuck: args.exclude_stuck.unwrap_or(true),
+        maximize_distance: args.maximize_distance.is_present(),
+        name_hash_key: args
+            .assign
+            .as_deref()
+            .filter(|&&assign| assign == Assign::NameHash)
+            .map(|_| args.key.as_deref().copied().unwrap_or_default()),
+        tag: args
+            .tag_bits
+            .as_deref()
+            .copied()
+            .zip(args.tag.as_deref().copied()),
+    };
+    specialize_expand(&ctx, enum_item)
+}
+
+/// An attribute macro for enums that enforces discriminant bit parity
+///
+/// See the [crate-level](https://docs.rs/enum_parity) docs for more examples.
+///
+/// # Macro Parameters
+/// - `even` enforces even bit parity
+/// - `odd` enforces odd bit parity
+/// - `allow_explicit_overrides` accepts a boolean. It causes an explicit enum discriminant that does not match the given bit partity to:
+///   - If `true`, successfully compile.
+///   - If `false`, fail to compile
+///
+///   `allow_explicit_overrides` is optional, and defaults to `false`.
+/// - `weight` accepts an integer `k`, and only assigns discriminants with exactly `k` bits set.
+///   This detects every unidirectional error (bits only flipping `1 -> 0`, or only `0 -> 1`).
+///   It is used instead of `even` or `odd`.
+/// - `berger` assigns each variant index to the low bits, with the number of zeros in the index in
+///   the high bits. This also detects every unidirectional error. It is used instead of `even` or
+///   `odd`.
+/// - `crc` assigns each variant index to the high bits, followed by its CRC in the low bits. It
+///   accepts either a preset name (`crc3-gsm`, `crc4-itu`, `crc5-usb`, `crc6-itu`, `crc7-mmc`,
+///   `crc8-smbus` or `crc8-autosar`), or a custom polynomial `crc(poly = 0x13, width = 4)`. Each
+///   preset has the initial value, reflection and final XOR it is catalogued with, so the CRC of
+///   an index that is a whole number of bytes matches the catalogued CRC of those bytes, in
+///   big-endian order, or little-endian if the preset reflects its input. A custom CRC also
+///   accepts `init`, `refin`, `refout` and `xorout`, which default to none, so it is the plain
+///   polynomial remainder of the index bits. It is used instead of `even` or `odd`.
+/// - `secded` only assigns extended Hamming codewords, which differ in at least 4 bits. It also
+///   generates a `correct` method, that corrects one flipped bit and detects two. Explicit
+///   overrides are only allowed if they keep that distance. It is used instead of `even` or `odd`.
+/// - `bch` accepts `t`, as in `bch(t = 2)`, and only assigns codewords of the longest binary BCH
+///   code that fits in the repr, which differ in at least `2t + 1` bits. It also generates a
+///   `correct` method, that corrects up to `t` flipped bits. Explicit overrides are only allowed if
+///   they keep that distance. It is used instead of `even` or `odd`.
+/// - `symbol_distance` accepts an integer `d`, and only assigns Reed-Solomon codewords over
+///   `symbol_bits` bit symbols, any two of which differ in at least `d` symbols. It also generates
+///   a `correct` method, that corrects up to `(d - 1) / 2` corrupted symbols, however many bits in
+///   each are flipped. `symbol_bits` accepts 2, 4 or 8, and defaults to 4. It is used instead of
+///   `even` or `odd`.
+/// - `check_matrix` accepts an array of rows, each a mask of the bits that one parity equation
+///   covers. Only discriminants with even parity under every row, i.e. a zero syndrome, are
+///   assigned, and explicit overrides are checked the same way. It also generates a
+///   `MIN_DISTANCE` constant, with the minimum distance of the code the matrix defines, or a lower
+///   bound on it if the code is too large to search. It is used instead of `even` or `odd`.
+/// - `gray` assigns the `i`-th Gray code to the `i`-th variant, so neighbouring variants differ in
+///   exactly one bit. With `gray(wrap)`, the last variant also differs from the first in exactly
+///   one bit, which needs an even number of variants. It is used instead of `even` or `odd`, and
+///   cannot be combined with `min_distance`.
+/// - `balanced` only assigns discriminants with exactly half of their bits set, for DC-free
+///   lines. With `balanced(manchester)`, each bit of the variant index is expanded to `01` for a
+///   `0`, or `10` for a `1`. It is used instead of `even` or `odd`.
+/// - `burst` accepts an integer `b`, and detects every burst of up to `b` adjacent flipped bits.
+///   The variant index is in the high bits, followed by `b` bits of interleaved parity. Every pair
+///   of discriminants is checked at expansion time, and explicit overrides are only allowed if no
+///   burst of up to `b` bits turns them into another variant. It is used instead of `even` or
+///   `odd`.
+/// - `complement` assigns each variant index to the low half of the bits, with its bitwise
+///   complement in the high half, as in `0xa55a`. It needs a fixed width repr of at least 16 bits,
+///   so not `u8`, `i8`, `usize` or `isize`, and is used instead of `even` or `odd`.
+/// - `check_digit` accepts `"damm"` or `"verhoeff"`, and only assigns discriminants whose decimal
+///   form is the variant index followed by its check digit, which catches every single mistyped
+///   digit and every swap of two adjacent digits. Explicit discriminants must have a valid check
+///   digit, even with `allow_explicit_overrides`. It also generates a `from_decimal` method, that
+///   parses a discriminant typed in by hand. It is used instead of `even` or `odd`.
+/// - `min_distance` accepts an integer `N`. Every pair of discriminants, including explicit ones,
+///   differ in at least `N` bits. It can be combined with `even` or `odd`, or used on its own.
+///   The values are searched in increasing order, and if the search takes too long, expansion
+///   fails with an error instead of hanging.
+/// - `maximize_distance` searches for discriminants that are far apart, and generates a
+///   `MIN_DISTANCE` constant with the smallest distance between any two of them. Two, three or
+///   four variants without explicit discriminants get the largest distance the repr allows, which
+///   is every bit for two, and two thirds of the bits for three or four. Otherwise greedy
+///   `min_distance` codes of up to 16 bits are searched, and repeated to fill wider reprs, so the
+///   search is quick, but may fall short of the largest distance. Either way, the same enum always
+///   gets the same discriminants. Explicit discriminants are kept, and count towards the distance.
+///   It is used on its own.
+/// - `exclude_stuck` accepts a boolean. If `true`, the all-zeros and all-ones values are never
+///   assigned, since that is what a dead bus or erased flash reads as. Every scheme that picks
+///   the next value that fits skips them. `gray` and `layout` give variant `i` a fixed value
+///   instead, so a variant whose value is all zeros or all ones fails to compile, rather than
+///   moving it and every later variant off their index. An explicit discriminant with one of those
+///   values also fails to compile, even with `allow_explicit_overrides`.
+///
+///   `exclude_stuck` is optional, and defaults to `true`.
+/// - `granularity` accepts `"word"`, `"byte"` or `"nibble"`. With `"byte"` or `"nibble"`, every
+///   byte or nibble of the discriminant has `even` or `odd` parity on its own, so one flipped bit
+///   in each of two bytes is still detected. It can only be combined with `even` or `odd`.
+///
+///   `granularity` is optional, and defaults to `"word"`.
+/// - `layout` accepts `"parity_msb"` or `"parity_lsb"`, and puts a single parity bit at a fixed
+///   position. Variant `i` is assigned `i | p << (W - 1)` with `"parity_msb"`, or `(i << 1) | p`
+///   with `"parity_lsb"`, where `p` gives the discriminant `even` or `odd` parity, and `W` is the
+///   number of bits in the repr, so `"parity_msb"` needs a fixed width repr. It can only be
+///   combined with `even` or `odd`.
+/// - `assign` accepts `"sequential"` or `"name_hash"`. With `"name_hash"`, each discriminant is
+///   derived from a keyed hash of the variant name, with its lowest bit flipped if needed for
+///   `even` or `odd` parity. The discriminants are spread over the whole repr, and stay the same
+///   when variants are reordered. A value that is taken, or too close to another discriminant, is
+///   hashed again, and variants are hashed in order of their names, so collisions are resolved the
+///   same way every time. It can only be combined with `even`, `odd` or `min_distance`.
+///
+///   `assign` is optional, and defaults to `"sequential"`.
+/// - `key` accepts an integer, which is hashed along with every variant name for
+///   `assign = "name_hash"`.
+///
+///   `key` is optional, and defaults to `0`.
+/// - `tag` and `tag_bits` accept integers, and reserve the high `tag_bits` bits of every
+///   discriminant for `tag`, so values from another enum with a different tag are never mistaken
+///   for this one. An explicit discriminant without the tag fails to compile, even with
+///   `allow_explicit_overrides`. It also generates a `decode` method, that rejects any value
+///   without the tag. It can only be combined with `even`, `odd`, `weight` or `min_distance`.
+///
+/// A variant can also be kept away from another specific variant, with
+/// `#[parity(distance_from(Other) >= N)]`. The two variants differ in at least `N` bits, on top of
+/// the scheme above. The constraint goes both ways, so whichever of the two is assigned first is
+/// kept away from an explicit discriminant on the other. Several constraints are separated by
+/// commas. Discriminants are still assigned in order, each one the first that fits, so a
+/// constraint that only an earlier variant moving could meet fails to compile, and needs an
+/// explicit discriminant instead.
+///
+/// The width of a `usize` or `isize` repr depends on the target, which the macro cannot see. So
+/// they cannot be combined with anything that places bits relative to the top of the word, or
+/// spreads them over all of it: `berger`, `balanced`, `complement`, `secded`, `bch`, `check_matrix`,
+/// `symbol_distance`, `granularity`, `layout = "parity_msb"`, `assign = "name_hash"`, `tag` or `maximize_distance`. With
+/// `exclude_stuck`, the all-ones value of every pointer width is excluded.
+///
+/// For an enum without fields, `TryFrom<repr>` is also implemented. A value that is not a
+/// discriminant is rejected with `ParityError::InvalidParity` if it fails the check of the scheme
+/// above, so it must have been corrupted, or with `ParityError::NoVariant` if it passes the check
+/// but no variant has it. Both carry the raw value.
+///
+/// Such an enum also gets `const fn from_repr` and `const fn to_repr`, and the `VARIANTS` and
+/// `DISCRIMINANTS` arrays in declaration order, so that it can be decoded in `const` items and
+/// `static` tables. Every enum gets `REMAINING_CAPACITY`, the number of variants that could still
+/// be added at the end without running out of values. With `min_distance`, it is only a lower
+/// bound when there are too many values to search through.
+///
+/// A fieldless enum also gets `const fn decode_nearest`, which decodes any value to the variant
+/// whose discriminant differs from it in the fewest bits, as `Decoded::Exact` or as
+/// `Decoded::Corrected` with the flipped bits. When several variants are equally near, which is
+/// usual after one flip under `even` or `odd`, they are all returned as `Decoded::Ambiguous`,
+/// rather than picking one of them.
+///
+/// For bits that are known to be unreliable, `const fn decode_with_erasures` leaves out every bit
+/// that is set in its mask of erased bits, and decodes to the only variant that agrees with the
+/// rest. Otherwise it returns `ErasureError::NoVariant`, or `ErasureError::Ambiguous` with every
+/// variant that agrees. A minimum distance of `d` fills in up to `d - 1` erased bits, so `even` and
+/// `odd` fill in one.
+///
+/// For a receiver with a confidence per bit, `const fn decode_soft` takes a log-likelihood ratio
+/// for each bit of the repr, lowest first, which is positive if the bit is more likely clear. For
+/// `usize` or `isize`, that is one for each bit of a pointer on the target. It scores every variant
+/// against them, and returns the most likely one, with the margin to the runner-up. So the enum
+/// can be decoded as a small block code, instead of only checked.
+///
+/// # Examples
+///
+/// ## Simple Usage
+/// In order to use even parity for enum discriminants:
+/// ```skip
+/// #[bit_parity(even)]
+/// ```
+///
+/// In order to use odd parity for enum discriminants:
+/// ```skip
+/// #[bit_parity(odd)]
+/// ```
+///
+/// ## Explicit Discriminant Values
+/// By default, assigning a value to an enum discriminant that does not have the matching bit parity fails to compile
+/// ```compile_fail
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// enum Foo {
+///   // this fails to compile, because `0x01` does not have even bit parity
+///   A = 0x01,
+///   B,
+///   C,
+/// }
+/// ```
+///
+/// If you want to allow explicit discriminants that do not match the given bit parity, add `allow_explicit_overrides`
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even, allow_explicit_overrides = true)]
+/// enum Foo {
+///   // `0x01` does not have even bit parity, but it is allowed from the `allow_explicit_overrides` parameter
+///   A = 0x01,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x01);
+/// // `B` and `C` will have even bit parity
+/// assert_eq!(Foo::B as u8, 0x03);
+/// assert_eq!(Foo::C as u8, 0x05);
+/// ```
+///
+/// ## Converting From the Repr
+/// ```
+/// # use enum_parity::{bit_parity, ParityError};
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::try_from(0x03), Ok(Foo::A));
+/// // one flipped bit fails the parity check
+/// assert_eq!(Foo::try_from(0x01), Err(ParityError::InvalidParity(0x01)));
+/// // even parity, but after the last variant
+/// assert_eq!(Foo::try_from(0x06), Err(ParityError::NoVariant(0x06)));
+/// ```
+///
+/// ## Constant Lookups
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// const B: Option<Foo> = Foo::from_repr(0x05);
+/// static TABLE: [u8; Foo::VARIANTS.len()] = Foo::DISCRIMINANTS;
+///
+/// assert_eq!(B, Some(Foo::B));
+/// assert_eq!(TABLE, [0x03, 0x05]);
+/// assert_eq!(Foo::A.to_repr(), 0x03);
+/// // every even value above `0x05`, other than the stuck `0xff`
+/// assert_eq!(Foo::REMAINING_CAPACITY, 124);
+/// ```
+///
+/// ## Nearest Variant
+/// ```
+/// # use enum_parity::{bit_parity, Decoded};
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::decode_nearest(0x05), Decoded::Exact(Foo::B));
+/// // only `B` is one bit away
+/// assert_eq!(
+///   Foo::decode_nearest(0x15),
+///   Decoded::Corrected { variant: Foo::B, flipped_mask: 0x10 },
+/// );
+/// // `A` and `B` are both one bit away
+/// let Decoded::Ambiguous { candidates } = Foo::decode_nearest(0x01) else {
+///   panic!();
+/// };
+/// assert_eq!(candidates.iter().collect::<Vec<_>>(), [&Foo::A, &Foo::B]);
+/// ```
+///
+/// ## Erased Bits
+/// ```
+/// # use enum_parity::{bit_parity, ErasureError};
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// // bit 1 is unreliable, but only `A` has even parity with the other bits
+/// assert_eq!(Foo::decode_with_erasures(0x01, 0x02), Ok(Foo::A));
+/// // with bits 1 and 2 unreliable, `A` and `B` both agree with the rest
+/// assert!(matches!(
+///   Foo::decode_with_erasures(0x01, 0x06),
+///   Err(ErasureError::Ambiguous(_)),
+/// ));
+/// ```
+///
+/// ## Soft Decisions
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// // `A` is 0x03, but bit 1 only barely reads as clear
+/// let mut llrs = [4.0; 8];
+/// llrs[0] = -4.0;
+/// llrs[1] = 0.5;
+///
+/// // the hard bits are 0x01, which is as near to `B`, but `A` is more likely
+/// assert_eq!(Foo::decode_soft(&llrs), (Foo::A, 7.0));
+/// ```
+///
+/// ## Constant Weight
+/// With `weight`, every discriminant has the same number of bits set
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(weight = 2)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x03);
+/// assert_eq!(Foo::B as u8, 0x05);
+/// assert_eq!(Foo::C as u8, 0x06);
+/// ```
+///
+/// ## Berger Codes
+/// A `u8` Berger code has 5 data bits, and 3 check bits that count the zeros in the data bits
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(berger)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0b101_00000);
+/// assert_eq!(Foo::B as u8, 0b100_00001);
+/// assert_eq!(Foo::C as u8, 0b100_00010);
+/// ```
+///
+/// ## CRC Protected Discriminants
+/// With `crc4-itu` in a `u8`, the high 4 bits are the variant index, and the low 4 bits are its
+/// CRC, computed over the index from its lowest bit up, as `crc4-itu` reflects its input
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(crc = "crc4-itu")]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x1d);
+/// assert_eq!(Foo::B as u8, 0x23);
+/// assert_eq!(Foo::C as u8, 0x3e);
+/// ```
+///
+/// ## Single Error Correction
+/// `secded` generates a `correct` method for enums without fields
+/// ```
+/// # use enum_parity::{bit_parity, Uncorrectable};
+/// #[repr(u8)]
+/// #[bit_parity(secded)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x0f);
+/// assert_eq!(Foo::correct(0x0f), Ok(Foo::A));
+/// // one flipped bit is corrected
+/// assert_eq!(Foo::correct(0x0d), Ok(Foo::A));
+/// // two flipped bits are detected
+/// assert_eq!(Foo::correct(0x0c), Err(Uncorrectable));
+/// ```
+///
+/// ## Multiple Error Correction
+/// `bch` corrects more flipped bits, for wider reprs
+/// ```
+/// # use enum_parity::{bit_parity, Uncorrectable};
+/// #[repr(u32)]
+/// #[bit_parity(bch(t = 2))]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::A as u32, 0x769);
+/// // two flipped bits are corrected
+/// assert_eq!(Foo::correct(0x76a), Ok(Foo::A));
+/// // three flipped bits are not
+/// assert_eq!(Foo::correct(0x76e), Err(Uncorrectable));
+/// ```
+///
+/// ## Symbol Errors
+/// `symbol_distance` corrects whole corrupted nibbles, such as a bad hex digit
+/// ```
+/// # use enum_parity::{bit_parity, Uncorrectable};
+/// #[repr(u16)]
+/// #[bit_parity(symbol_distance = 3, symbol_bits = 4)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::A as u16, 0x168);
+/// // one corrupted nibble is corrected
+/// assert_eq!(Foo::correct(0xf68), Ok(Foo::A));
+/// // two are not
+/// assert_eq!(Foo::correct(0x078), Err(Uncorrectable));
+/// ```
+///
+/// ## Parity-Check Matrix
+/// Any linear code, such as the extended Hamming code
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(check_matrix = [0b0101_0101, 0b0110_0110, 0b0111_1000, 0b1111_1111])]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x1e);
+/// assert_eq!(Foo::B as u8, 0x2d);
+/// assert_eq!(Foo::MIN_DISTANCE, 4);
+/// ```
+///
+/// ## Gray Codes
+/// The first Gray code is all zeros, so `exclude_stuck` has to allow it
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(gray(wrap), exclude_stuck = false)]
+/// enum State {
+///   Idle,
+///   Start,
+///   Run,
+///   Stop,
+///   Fault,
+///   Reset,
+/// }
+///
+/// assert_eq!(State::Idle as u8, 0b000);
+/// assert_eq!(State::Start as u8, 0b001);
+/// assert_eq!(State::Run as u8, 0b011);
+/// assert_eq!(State::Stop as u8, 0b111);
+/// assert_eq!(State::Fault as u8, 0b101);
+/// // `Reset` also differs from `Idle` in one bit
+/// assert_eq!(State::Reset as u8, 0b100);
+/// ```
+///
+/// ## Balanced Codes
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(balanced)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// #[repr(u8)]
+/// #[bit_parity(balanced(manchester))]
+/// enum Bar {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0b0000_1111);
+/// assert_eq!(Foo::B as u8, 0b0001_0111);
+///
+/// assert_eq!(Bar::A as u8, 0b01_01_01_01);
+/// assert_eq!(Bar::B as u8, 0b01_01_01_10);
+/// ```
+///
+/// ## Stuck-At Values
+/// The all-zeros and all-ones values are skipped by default
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// #[repr(u8)]
+/// #[bit_parity(even, exclude_stuck = false)]
+/// enum Bar {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x03);
+/// assert_eq!(Bar::A as u8, 0x00);
+/// ```
+///
+/// ```compile_fail
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(odd, allow_explicit_overrides = true)]
+/// enum Foo {
+///   A,
+///   // this fails to compile, because every bit is set
+///   B = 0xff,
+/// }
+/// ```
+///
+/// ## Parity per Byte
+/// The variant index is spread over the bytes, with the lowest bit of each byte as its parity bit
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u16)]
+/// #[bit_parity(even, granularity = "byte")]
+/// enum Foo {
+///   A,
+///   B,
+///   C = 0x00ff,
+///   // the low byte has run out, so the high byte is next
+///   D,
+/// }
+///
+/// assert_eq!(Foo::A as u16, 0x0003);
+/// assert_eq!(Foo::B as u16, 0x0005);
+/// assert_eq!(Foo::D as u16, 0x0300);
+/// ```
+///
+/// ## Fixed Parity Bit
+/// The variant index is in the low bits, so the discriminants can be computed by hand. Index 0 is
+/// all zeros, so `exclude_stuck` has to allow it
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even, layout = "parity_msb", exclude_stuck = false)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x00);
+/// assert_eq!(Foo::B as u8, 0x81);
+/// assert_eq!(Foo::C as u8, 0x82);
+/// ```
+///
+/// ## Burst Error Detection
+/// Parity only detects bursts of an odd length, but `burst = 3` detects every burst of up to 3 bits
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(burst = 3)]
+/// enum Foo {
+///   A,
+///   B,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0b00001_001);
+/// assert_eq!(Foo::B as u8, 0b00010_010);
+/// ```
+///
+/// ## Complement Pairs
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u16)]
+/// #[bit_parity(complement)]
+/// enum SafetyState {
+///   Init,
+///   Safe,
+///   Armed = 0xa55a,
+/// }
+///
+/// assert_eq!(SafetyState::Init as u16, 0xff00);
+/// assert_eq!(SafetyState::Safe as u16, 0xfe01);
+/// ```
+///
+/// ## Check Digits
+/// For codes that operators type in by hand
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u16)]
+/// #[bit_parity(check_digit = "damm")]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum Fault {
+///   Overheat,
+///   Jammed = 5724,
+/// }
+///
+/// assert_eq!(Fault::Overheat as u16, 13);
+/// assert_eq!(Fault::from_decimal("5724"), Some(Fault::Jammed));
+/// // two digits swapped
+/// assert_eq!(Fault::from_decimal("5742"), None);
+/// ```
+///
+/// ## Hashed Variant Names
+/// Discriminants are spread over the whole repr, so a random corrupted value is almost never
+/// valid, and reordering the variants does not change them
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u32)]
+/// #[bit_parity(even, assign = "name_hash", key = 0x1234)]
+/// enum Command {
+///   Stop,
+///   Idle,
+///   Start,
+/// }
+///
+/// assert_eq!(Command::Idle as u32, 0x4491_ce7e);
+/// assert_eq!(Command::Start as u32, 0x5891_abfb);
+/// assert_eq!(Command::Stop as u32, 0xeafa_c277);
+/// ```
+///
+/// ## Tag Bits
+/// Both enums use even parity, but a status is never decoded as a command
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even, tag = 0b101, tag_bits = 3)]
+/// # #[derive(Debug, Eq, PartialEq)]
+/// enum CommandId {
+///   Start,
+///   Stop,
+/// }
+///
+/// #[repr(u8)]
+/// #[bit_parity(even, tag = 0b011, tag_bits = 3)]
+/// enum StatusId {
+///   Ok,
+///   Busy,
+/// }
+///
+/// assert_eq!(CommandId::Stop as u8, 0xa3);
+/// assert_eq!(CommandId::decode(0xa3), Some(CommandId::Stop));
+/// assert_eq!(StatusId::Busy as u8, 0x63);
+/// assert_eq!(CommandId::decode(StatusId::Busy as u8), None);
+/// ```
+///
+/// ## Distance Between Variants
+/// A single corrupted bit should not turn `Safe` into anything near `Armed`
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(even)]
+/// enum Interlock {
+///   Safe,
+///   Idle,
+///   #[parity(distance_from(Safe) >= 6)]
+///   Armed,
+/// }
+///
+/// assert_eq!(Interlock::Safe as u8, 0x03);
+/// assert_eq!(Interlock::Idle as u8, 0x05);
+/// assert_eq!(Interlock::Armed as u8, 0x3c);
+/// ```
+///
+/// ## Minimum Distance
+/// Bit parity only guarantees that discriminants differ in at least 2 bits, so two bit flips can
+/// turn one variant into another. `min_distance` picks discriminants that are further apart
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(min_distance = 3)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+/// }
+///
+/// assert_eq!(Foo::A as u8, 0x01);
+/// assert_eq!(Foo::B as u8, 0x06);
+/// assert_eq!(Foo::C as u8, 0x18);
+/// ```
+///
+/// `maximize_distance` picks the distance for you
+/// ```
+/// # use enum_parity::bit_parity;
+/// #[repr(u8)]
+/// #[bit_parity(maximize_distance)]
+/// enum Foo {
+///   A,
+///   B,
+///   C,
+///   D,
+/// }
+///
+/// assert_eq!(Foo::MIN_DISTANCE, 5);
+/// assert_eq!(Foo::A as u8, 0x01);
+/// assert_eq!(Foo::B as u8, 0xf9);
+/// ```
+///
+#[proc_macro_attribute]
+pub fn bit_parity(
+    args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let args = parse_macro_input!(args as BitParityArgs);
+    let enum_item = parse_macro_input!(input as ItemEnum);
+
+    try_expand(&args, enum_item).map_or_else(|e| e.into_compile_error().into(), Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+
+    use super::{ARGS, BitParityArgs};
+
+    /// The names darling parses `bit_parity` arguments from, read from the source of the
+    /// argument struct and the parity enums, so that none can be missed from [`ARGS`]
+    fn parsed_args() -> Vec<String> {
+        let file = syn::parse_file(include_str!("lib.rs")).unwrap();
+        let darling_flag = |attrs: &[syn::Attribute], flag: &str| {
+            attrs.iter().any(|attr| {
+                attr.path().is_ident("darling")
+                    && attr
+                        .parse_args::<syn::Ident>()
+                        .is_ok_and(|ident| ident == flag)
+            })
+        };
+        let mut args = Vec::new();
+        for item in file.items {
+            match item {
+                syn::Item::Struct(item) if item.ident == "BitParityArgs" => args.extend(
+                    item.fields
+                        .iter()
+                        .filter(|field| !darling_flag(&field.attrs, "flatten"))
+                        .map(|field| field.ident.as_ref().unwrap().to_string()),
+                ),
+                syn::Item::Enum(item) if item.ident == "Parity" || item.ident == "Correcting" => {
+                    args.extend(
+                        item.variants
+                            .iter()
+                            .filter(|variant| !darling_flag(&variant.attrs, "skip"))
+                            .map(|variant| snake_case(&variant.ident.to_string())),
+                    );
+                }
+                _ => {}
+            }
+        }
+        args.sort();
+        args
+    }
+
+    fn snake_case(name: &str) -> String {
+        name.chars().fold(String::new(), |mut snake, c| {
+            if c.is_uppercase() && !snake.is_empty() {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+            snake
+        })
+    }
+
+    #[test]
+    pub fn args_match_parsed_args() {
+        assert!(ARGS.is_sorted());
+        assert_eq!(ARGS, parsed_args());
+    }
+
+    #[test]
+    pub fn every_arg_is_known() {
+        for arg in ARGS {
+            let items = [syn::parse_str(arg).unwrap()];
+            if let Err(err) = BitParityArgs::from_list(&items) {
+                assert!(
+                    !err.to_string().starts_with("Unknown field"),
+                    "{arg}: {err}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+//! Discriminants from a keyed hash of the variant name
+//!
+//! Each discriminant is derived from the key and the variant ident alone, so it does not change
+//! when variants are reordered, and the discriminants are spread over the whole repr. The lowest
+//! bit is flipped when needed to give the requested parity. A value that is already taken, or
+//! otherwise not allowed, is hashed again with the next attempt number. Variants are hashed in
+//! order of their names, so collisions are also resolved the same way in any declaration order.
+
+use darling::FromMeta;
+
+/// How discriminants are picked for variants without an explicit one
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub enum Assign {
+    #[default]
+    Sequential,
+    NameHash,
+}
+
+/// the number of hashes tried for a single variant before giving up
+pub const MAX_ATTEMPTS: u32 = 1 << 16;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// the splitmix64 finalizer, which spreads every input bit over the whole output
+const fn mix(mut val: u64) -> u64 {
+    val = (val ^ (val >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    val = (val ^ (val >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    val ^ (val >> 31)
+}
+
+/// The `attempt`-th hash of `name` with `key`
+pub fn hash(key: u64, name: &str, attempt: u32) -> u128 {
+    let name = name.bytes().fold(FNV_OFFSET ^ key, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    });
+    let low = mix(name ^ mix(u64::from(attempt)));
+    let high = mix(low ^ key);
+
+    u128::from(high) << 64 | u128::from(low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hash;
+
+    #[test]
+    pub fn stable() {
+        // these are baked into user discriminants, so they must never change
+        assert_eq!(hash(0, "", 0) & u128::from(u64::MAX), 0xf52a_15e9_a9b5_e89b);
+        assert_eq!(hash(0x1234, "Idle", 0) & u128::from(u32::MAX), 0x4491_ce7f);
+    }
+
+    #[test]
+    pub fn depends_on_everything() {
+        let base = hash(0x1234, "Idle", 0);
+
+        assert_ne!(hash(0x1235, "Idle", 0), base);
+        assert_ne!(hash(0x1234, "Idla", 0), base);
+        assert_ne!(hash(0x1234, "Idle", 1), base);
+        assert_eq!(hash(0x1234, "Idle", 0), base);
+    }
+}
@@ -0,0 +1,267 @@
+//! The parity check of a raw value, as it is generated for `TryFrom`
+//!
+//! Most codes are affine, i.e. the codewords are a linear code shifted by the codeword at index 0,
+//! so they are checked by the parity of the value under each row of a parity-check matrix. That
+//! matrix is the null space of the codewords at each power of two index. The codes that are not
+//! affine are checked by what they count directly.
+
+use std::ops::Range;
+
+use darling::ToTokens;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    Correcting, Parity, berger,
+    bit_par_iter::IntegerParity,
+    check_digit::CheckDigit,
+    check_matrix::{binomial, null_space},
+};
+
+/// How a raw value is checked for being a codeword, before it is matched against the variants
+pub enum ParityCheck {
+    /// every value passes, since there is no redundancy
+    Any,
+    /// the parity of the value under each row is the matching bit of `expected`
+    Affine { rows: Vec<u128>, expected: u128 },
+    /// exactly this many bits are set
+    Weight(u32),
+    /// the bits above `data_bits` count the zeros below them
+    Berger { data_bits: u32 },
+    /// the index, decoded from the Gray code, is not `skipped`
+    Gray { skipped: Range<u128> },
+    /// the decimal form ends in a valid check digit
+    Decimal(CheckDigit),
+}
+
+impl ParityCheck {
+    pub fn new(parity: Option<&Parity>, bits: u32) -> Self {
+        let ones = u128::MAX >> (u128::BITS - bits);
+        match parity {
+            None => Self::Any,
+            Some(Parity::Even) => Self::Affine {
+                rows: vec![ones],
+                expected: 0,
+            },
+            Some(Parity::Odd) => Self::Affine {
+                rows: vec![ones],
+                expected: 1,
+            },
+            Some(&Parity::Weight(weight)) => Self::Weight(weight),
+            Some(Parity::Balanced(balanced)) if !balanced.is_manchester() => Self::Weight(bits / 2),
+            Some(Parity::Berger) => Self::Berger {
+                data_bits: berger::data_bits(bits),
+            },
+            Some(Parity::Correcting(Correcting::CheckMatrix(matrix))) => Self::Affine {
+                rows: matrix.rows().to_vec(),
+                expected: 0,
+            },
+            // every value is the Gray code of some index, unless it is skipped by a wrapping code
+            Some(Parity::Gray(gray)) if !gray.wraps() => Self::Any,
+            Some(Parity::Gray(gray)) => Self::Gray {
+                skipped: gray.skipped(),
+            },
+            Some(&Parity::CheckDigit(check_digit)) => Self::Decimal(check_digit),
+            Some(parity) => Self::affine(parity, bits),
+        }
+    }
+
+    /// The parity-check matrix of a systematic code that is affine in the variant index
+    fn affine(parity: &Parity, bits: u32) -> Self {
+        let Some(base) = parity.encode(0, bits) else {
+            return Self::Any;
+        };
+        let generators = (0..u128::BITS)
+            .map_while(|bit| parity.encode(1 << bit, bits))
+            .map(|code| code ^ base)
+            .collect::<Vec<_>>();
+        let rows = null_space(&generators, bits);
+        let expected = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| (*row & base).count_ones() % 2 == 1)
+            .fold(0, |expected, (idx, _)| expected | 1 << idx);
+
+        Self::Affine { rows, expected }
+    }
+
+    /// Whether `code` passes, or `None` if only the generated expression can tell
+    pub fn passes(&self, code: u128) -> Option<bool> {
+        match self {
+            Self::Any => Some(true),
+            Self::Affine { rows, expected } => Some(syndrome(rows, code) == *expected),
+            Self::Weight(weight) => Some(code.count_ones() == *weight),
+            Self::Berger { .. } | Self::Gray { .. } | Self::Decimal(_) => None,
+        }
+    }
+
+    /// The number of values below `end` that pass, or `None` if they cannot be counted directly
+    ///
+    /// Each set bit of `end` stands for every value that matches `end` above it and has it clear,
+    /// with any bits below it. Those are counted in closed form.
+    pub fn count_below(&self, end: u128, bits: u32) -> Option<u128> {
+        let set_bits = (0..bits).filter(|bit| end >> bit & 1 == 1);
+        match self {
+            Self::Any => Some(end),
+            Self::Affine { rows, expected } => {
+                // a basis of the syndromes of the bits below the current one, by highest bit
+                let mut basis = [0_u128; u128::BITS as usize];
+                let mut rank = 0;
+                let mut count = 0;
+                for bit in 0..bits {
+                    if end >> bit & 1 == 1 {
+                        let high = end >> bit >> 1 << 1 << bit;
+                        if reduce(&basis, syndrome(rows, high) ^ expected) == 0 {
+                            count += 1 << (bit - rank);
+                        }
+                    }
+                    let column = reduce(&basis, syndrome(rows, 1 << bit));
+                    if column != 0 {
+                        basis[column.ilog2() as usize] = column;
+                        rank += 1;
+                    }
+                }
+                Some(count)
+            }
+            Self::Weight(weight) => Some(
+                set_bits
+                    .filter_map(|bit| {
+                        let high = (end >> bit >> 1).count_ones();
+                        binomial(bit, weight.checked_sub(high)?)
+                    })
+                    .sum(),
+            ),
+            Self::Berger { .. } | Self::Gray { .. } | Self::Decimal(_) => None,
+        }
+    }
+
+    /// An expression that is `true` if `raw` passes the check
+    pub fn expr<T>(&self) -> TokenStream
+    where
+        T: IntegerParity + ToTokens,
+    {
+        let bits = T::BITS;
+        // the bits of `raw` as they are, so that the high bit of a signed repr is not sign extended
+        let ones = u128::MAX >> (u128::BITS - bits);
+        match self {
+            Self::Any => quote!(true),
+            Self::Affine { rows, expected } => {
+                let checks = rows.iter().enumerate().map(|(idx, &row)| {
+                    let row = T::from_bits(row);
+                    let parity = u32::from(expected >> idx & 1 == 1);
+                    quote!((raw & #row).count_ones() % 2 == #parity)
+                });
+                quote!(true #(&& #checks)*)
+            }
+            Self::Weight(weight) => quote!(raw.count_ones() == #weight),
+            Self::Berger { data_bits } => {
+                let mask = T::from_bits((1 << data_bits) - 1);
+                quote!(
+                    (raw as u128 & #ones) >> #data_bits
+                        == (#data_bits - (raw & #mask).count_ones()) as u128
+                )
+            }
+            Self::Gray { skipped } => {
+                let (start, end) = (skipped.start, skipped.end);
+                quote! {{
+                    let code = raw as u128 & #ones;
+                    let mut index = code;
+                    let mut shift = 1;
+                    while shift < #bits {
+                        index ^= index >> shift;
+                        shift <<= 1;
+                    }
+                    index < #start || index >= #end
+                }}
+            }
+            Self::Decimal(check_digit) => {
+                let max = check_digit.max(bits);
+                let validate = check_digit.validate();
+                quote! {{
+                    let mut val = raw as u128 & #ones;
+                    if val > #max {
+                        false
+                    } else {
+                        let mut digits = [b'0'; 39];
+                        let mut start = digits.len();
+                        loop {
+                            start -= 1;
+                            digits[start] = b'0' + (val % 10) as u8;
+                            val /= 10;
+                            if val == 0 {
+                                break;
+                            }
+                        }
+                        let (_, bytes) = digits.split_at(start);
+                        #validate
+                    }
+                }}
+            }
+        }
+    }
+}
+
+/// the bits of the syndrome of `code`, where bit `i` is set if row `i` has odd parity
+fn syndrome(rows: &[u128], code: u128) -> u128 {
+    rows.iter()
+        .enumerate()
+        .filter(|(_, row)| (*row & code).count_ones() % 2 == 1)
+        .fold(0, |syndrome, (idx, _)| syndrome | 1 << idx)
+}
+
+/// `val` with every vector of `basis` that covers its highest bit cleared out of it
+fn reduce(basis: &[u128], mut val: u128) -> u128 {
+    while val != 0 && basis[val.ilog2() as usize] != 0 {
+        val ^= basis[val.ilog2() as usize];
+    }
+    val
+}
+
+#[cfg(test)]
+mod tests {
+    use darling::FromMeta;
+
+    use super::ParityCheck;
+    use crate::{Correcting, Parity, crc::Crc, reed_solomon::ReedSolomon};
+
+    /// whether `code` passes an affine check, as the generated expression does
+    fn passes(check: &ParityCheck, code: u128) -> bool {
+        let ParityCheck::Affine { rows, expected } = check else {
+            panic!("not an affine check");
+        };
+        rows.iter()
+            .enumerate()
+            .all(|(idx, row)| (row & code).count_ones() % 2 == u32::from(expected >> idx & 1 == 1))
+    }
+
+    #[test]
+    pub fn affine_matches_decode() {
+        let parities = [
+            Parity::Correcting(Correcting::Secded),
+            Parity::Complement,
+            Parity::Burst(3),
+            Parity::Crc(Crc::from_string("crc4-itu").unwrap()),
+            Parity::Correcting(Correcting::ReedSolomon(ReedSolomon::new(3, 4).unwrap())),
+        ];
+        for parity in parities {
+            let check = ParityCheck::new(Some(&parity), 16);
+            for code in 0..1 << 16 {
+                assert_eq!(
+                    passes(&check, code),
+                    parity.decode(code, 16).is_some(),
+                    "{parity} {code:#x}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn even_and_odd() {
+        let even = ParityCheck::new(Some(&Parity::Even), 8);
+        let odd = ParityCheck::new(Some(&Parity::Odd), 8);
+
+        assert!(passes(&even, 0x03));
+        assert!(!passes(&even, 0x01));
+        assert!(passes(&odd, 0x01));
+    }
+}
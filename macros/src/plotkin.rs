@@ -0,0 +1,91 @@
+//! Codes of two to four values that meet the Plotkin bound
+//!
+//! Two values can differ in every bit, as a value and its complement do. Three or four values in
+//! `n` bits can only be `2n / 3` bits apart: each bit differs between at most two of the three
+//! pairs of three values, or four of the six pairs of four. That is met by splitting the bits into
+//! three runs, as evenly as possible, and giving each pair of values a run to agree in.
+//!
+//! Distances are the same after XOR-ing every value with the same mask, so the code can be moved
+//! off any value without losing its distance.
+
+/// The largest minimum distance that `len` values of `bits` bits can have, or `None` if there are
+/// too few values to have a distance, or too many to know it
+pub const fn distance(len: usize, bits: u32) -> Option<u32> {
+    match len {
+        2 => Some(bits),
+        3 | 4 => Some(2 * bits / 3),
+        _ => None,
+    }
+}
+
+/// `len` values of `bits` bits, the first of them zero, that are [`distance`] apart
+pub fn code(len: usize, bits: u32) -> Option<Vec<u128>> {
+    let ones = |width: u32| u128::MAX.checked_shr(u128::BITS - width).unwrap_or(0);
+    let code = match len {
+        2 => vec![0, ones(bits)],
+        3 | 4 => {
+            // the runs differ in length by at most one, with the longest at the bottom
+            let low = bits.div_ceil(3);
+            let mid = (bits - low).div_ceil(2);
+            let low_run = ones(low);
+            let mid_run = ones(mid) << low;
+            let high_run = ones(bits) & !low_run & !mid_run;
+            let mut code = vec![0, mid_run | high_run, low_run | high_run, low_run | mid_run];
+            code.truncate(len);
+            code
+        }
+        _ => return None,
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::{code, distance};
+
+    #[test]
+    pub fn runs() {
+        assert_eq!(code(2, 8), Some(vec![0x00, 0xff]));
+        assert_eq!(code(4, 8), Some(vec![0x00, 0xf8, 0xc7, 0x3f]));
+        assert_eq!(code(3, 32), Some(vec![0, 0xffff_f800, 0xffc0_07ff]));
+        assert_eq!(code(5, 8), None);
+    }
+
+    #[test]
+    pub fn meets_distance() {
+        for bits in [8, 16, 32, 64, 128] {
+            for len in 2..=4 {
+                let code = code(len, bits).unwrap();
+                let min = code
+                    .iter()
+                    .tuple_combinations()
+                    .map(|(a, b)| (a ^ b).count_ones())
+                    .min();
+                assert_eq!(code.len(), len);
+                assert_eq!(min, distance(len, bits));
+            }
+        }
+    }
+
+    /// the bound, checked against every code of up to 4 values of 6 bits
+    #[test]
+    pub fn nothing_beats_distance() {
+        let bits = 6;
+        for len in 2..=4 {
+            let best = (1..1_u32 << bits)
+                .combinations(len - 1)
+                .map(|rest| {
+                    rest.iter()
+                        .chain([&0])
+                        .tuple_combinations()
+                        .map(|(a, b)| (a ^ b).count_ones())
+                        .min()
+                        .unwrap_or_default()
+                })
+                .max();
+            assert_eq!(best, distance(len, bits));
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Reed-Solomon codes over nibbles, or other small symbols
+//!
+//! The discriminant is split into `symbol_bits` bit symbols, each an element of
+//! `GF(2^symbol_bits)`. The variant index is in the high symbols, followed by `d - 1` check
+//! symbols, which are the remainder of the index polynomial modulo
+//! `(x - α)(x - α^2)..(x - α^(d - 1))`. A Reed-Solomon code is maximum distance separable, so any
+//! two codewords differ in at least `d` symbols, and up to `(d - 1) / 2` corrupted symbols can be
+//! corrected. The code has at most `2^symbol_bits - 1` symbols, so any symbols above that are left
+//! clear.
+
+use std::fmt::Display;
+
+/// A primitive polynomial for each supported symbol size, including the `x^symbol_bits` term
+const PRIMITIVE: &[(u32, u32)] = &[(2, 0b111), (4, 0x13), (8, 0x11d)];
+
+/// A Reed-Solomon code with a minimum distance of `distance` symbols
+#[derive(Copy, Clone, Debug)]
+pub struct ReedSolomon {
+    distance: u32,
+    symbol_bits: u32,
+}
+
+impl ReedSolomon {
+    /// `None` if there is no field for `symbol_bits`
+    pub fn new(distance: u32, symbol_bits: u32) -> Option<Self> {
+        PRIMITIVE
+            .iter()
+            .any(|&(bits, _)| bits == symbol_bits)
+            .then_some(Self {
+                distance,
+                symbol_bits,
+            })
+    }
+
+    /// every supported symbol size
+    pub fn symbol_sizes() -> impl Iterator<Item = u32> {
+        PRIMITIVE.iter().map(|&(bits, _)| bits)
+    }
+
+    pub const fn distance(self) -> u32 {
+        self.distance
+    }
+
+    pub const fn symbol_bits(self) -> u32 {
+        self.symbol_bits
+    }
+
+    /// the number of corrupted symbols that can be corrected
+    pub const fn radius(self) -> u32 {
+        self.distance.saturating_sub(1) / 2
+    }
+
+    /// the number of symbols that differ between `a` and `b`
+    pub fn symbol_distance(self, a: u128, b: u128) -> u32 {
+        let mask = (1 << self.symbol_bits) - 1;
+        let diff = a ^ b;
+        (0..u128::BITS / self.symbol_bits)
+            .filter(|symbol| diff >> (symbol * self.symbol_bits) & mask != 0)
+            .map(|_| 1)
+            .sum()
+    }
+
+    /// the number of symbols in the code, in a `bits` bit repr
+    fn length(self, bits: u32) -> u32 {
+        (bits / self.symbol_bits).min((1 << self.symbol_bits) - 1)
+    }
+
+    /// the number of check symbols
+    const fn check_symbols(self) -> u32 {
+        self.distance.saturating_sub(1)
+    }
+
+    /// the number of index symbols, or `None` if not even the check symbols fit
+    fn data_symbols(self, bits: u32) -> Option<u32> {
+        self.length(bits).checked_sub(self.check_symbols())
+    }
+
+    /// the number of codewords that fit in `bits` bits
+    pub fn capacity(self, bits: u32) -> u128 {
+        self.data_symbols(bits)
+            .map_or(0, |data| 1 << (data * self.symbol_bits))
+    }
+
+    /// The powers of `α`, as polynomials over GF(2) reduced by the primitive polynomial
+    fn exp_table(self) -> Vec<u32> {
+        let poly = PRIMITIVE
+            .iter()
+            .find(|&&(bits, _)| bits == self.symbol_bits)
+            .map_or(0, |&(_, poly)| poly);
+        let order = (1 << self.symbol_bits) - 1;
+        let mut exp = Vec::with_capacity(order);
+        let mut elem = 1;
+        for _ in 0..order {
+            exp.push(elem);
+            elem <<= 1;
+            if elem >> self.symbol_bits & 1 == 1 {
+                elem ^= poly;
+            }
+        }
+        exp
+    }
+
+    /// the check symbols of `index`, lowest first
+    fn checksum(self, index: u128) -> u128 {
+        let exp = self.exp_table();
+        let log = |elem: u32| exp.iter().position(|&e| e == elem).unwrap_or_default();
+        let mul = |a: u32, b: u32| {
+            if a == 0 || b == 0 {
+                0
+            } else {
+                exp[(log(a) + log(b)) % exp.len()]
+            }
+        };
+
+        // the generator polynomial, lowest coefficient first, which is monic
+        let mut generator = vec![1];
+        for power in 1..=self.check_symbols() as usize {
+            let root = exp[power % exp.len()];
+            let mut next = vec![0; generator.len() + 1];
+            for (deg, &coeff) in generator.iter().enumerate() {
+                next[deg + 1] ^= coeff;
+                next[deg] ^= mul(coeff, root);
+            }
+            generator = next;
+        }
+
+        // long division of `index * x^check_symbols`, from the highest symbol down
+        let mask = (1 << self.symbol_bits) - 1;
+        let checks = self.check_symbols() as usize;
+        let mut rem = vec![0; checks];
+        for symbol in (0..u128::BITS / self.symbol_bits).rev() {
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "symbols are masked to 8 bits"
+            )]
+            let data = (index >> (symbol * self.symbol_bits) & mask) as u32;
+            let feedback = data ^ rem.last().copied().unwrap_or_default();
+            rem.rotate_right(1);
+            if let Some(lowest) = rem.first_mut() {
+                *lowest = 0;
+            }
+            for (deg, coeff) in rem.iter_mut().enumerate() {
+                *coeff ^= mul(feedback, generator[deg]);
+            }
+        }
+
+        rem.iter().rev().fold(0, |checksum, &coeff| {
+            checksum << self.symbol_bits | u128::from(coeff)
+        })
+    }
+
+    pub fn encode(self, index: u128, bits: u32) -> Option<u128> {
+        let data = self.data_symbols(bits)?;
+        if index.checked_shr(data * self.symbol_bits).unwrap_or(0) != 0 {
+            return None;
+        }
+
+        Some(index << (self.check_symbols() * self.symbol_bits) | self.checksum(index))
+    }
+
+    /// the index of `code`, or `None` if it is not a valid codeword
+    pub fn decode(self, code: u128, bits: u32) -> Option<u128> {
+        let index = code.checked_shr(self.check_symbols() * self.symbol_bits)?;
+        (self.encode(index, bits) == Some(code)).then_some(index)
+    }
+}
+
+impl Display for ReedSolomon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "symbol_distance = {}, symbol_bits = {}",
+            self.distance, self.symbol_bits
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReedSolomon;
+
+    fn nibbles(distance: u32) -> ReedSolomon {
+        ReedSolomon::new(distance, 4).unwrap()
+    }
+
+    #[test]
+    pub fn capacity_per_repr() {
+        assert_eq!(nibbles(3).capacity(16), 1 << 8);
+        assert_eq!(nibbles(3).capacity(32), 1 << 24);
+        // only 15 nibbles fit in a code over GF(16)
+        assert_eq!(nibbles(5).capacity(64), 1 << 44);
+        // no index symbols are left, so only the zero codeword
+        assert_eq!(nibbles(5).capacity(16), 1);
+        assert_eq!(nibbles(6).capacity(16), 0);
+        assert!(ReedSolomon::new(3, 5).is_none());
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for (rs, bits) in [(nibbles(3), 16), (nibbles(5), 32), (nibbles(7), 64)] {
+            for index in [0, 1, 2, 0x5a, rs.capacity(bits) - 1] {
+                let code = rs.encode(index, bits).unwrap();
+                assert_eq!(rs.decode(code, bits), Some(index));
+                assert_eq!(rs.decode(code ^ 0x10, bits), None);
+            }
+        }
+    }
+
+    #[test]
+    pub fn minimum_symbol_distance() {
+        for (rs, bits) in [(nibbles(3), 16), (nibbles(4), 32)] {
+            let codes = (0..256)
+                .map(|index| rs.encode(index, bits).unwrap())
+                .collect::<Vec<_>>();
+            for (idx, &a) in codes.iter().enumerate() {
+                for &b in &codes[..idx] {
+                    assert!(rs.symbol_distance(a, b) >= rs.distance(), "{a:#x} {b:#x}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    pub fn bytes() {
+        let rs = ReedSolomon::new(3, 8).unwrap();
+        let code = rs.encode(0x1234, 32).unwrap();
+
+        assert_eq!(code >> 16, 0x1234);
+        assert_eq!(rs.symbol_distance(code, 0), 4);
+        assert_eq!(rs.radius(), 1);
+    }
+}
@@ -0,0 +1,51 @@
+//! A short value, repeated to fill the repr
+//!
+//! `maximize_distance` searches for codes of at most [`MAX_WIDTH`] bits, and repeats them to fill
+//! wider reprs. Every copy holds the same value, so two discriminants differ in `copies` times as
+//! many bits as their values do.
+
+/// the widest value that is searched
+pub const MAX_WIDTH: u32 = 16;
+
+/// every number of copies that fills `bits` bits with values of at most [`MAX_WIDTH`] bits, from
+/// the fewest copies to the most
+pub fn copies(bits: u32) -> impl Iterator<Item = u32> {
+    (0..=bits.trailing_zeros())
+        .map(|shift| 1 << shift)
+        .filter(move |copies| bits / copies <= MAX_WIDTH)
+}
+
+pub fn encode(value: u128, copies: u32, bits: u32) -> Option<u128> {
+    let width = bits / copies;
+    if value.checked_shr(width).unwrap_or(0) != 0 {
+        return None;
+    }
+
+    Some((0..copies).fold(0, |code, copy| code | value << (copy * width)))
+}
+
+/// the repeated value of `code`, or `None` if the copies differ
+pub fn decode(code: u128, copies: u32, bits: u32) -> Option<u128> {
+    let value = code & (u128::MAX >> (u128::BITS - bits / copies));
+    (encode(value, copies, bits) == Some(code)).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{copies, decode, encode};
+
+    #[test]
+    pub fn copies_per_repr() {
+        assert!(copies(8).eq([1, 2, 4, 8]));
+        assert!(copies(32).eq([2, 4, 8, 16, 32]));
+        assert!(copies(128).eq([8, 16, 32, 64, 128]));
+    }
+
+    #[test]
+    pub fn round_trip() {
+        assert_eq!(encode(0x5a, 4, 32), Some(0x5a5a_5a5a));
+        assert_eq!(encode(0x15a, 4, 32), None);
+        assert_eq!(decode(0x5a5a_5a5a, 4, 32), Some(0x5a));
+        assert_eq!(decode(0x5a5a_5a5b, 4, 32), None);
+    }
+}
@@ -0,0 +1,111 @@
+//! Extended Hamming (SECDED) codes
+//!
+//! Bit `i` of the discriminant is position `i` of the code. The power of two positions hold the
+//! Hamming check bits, position 0 holds the parity of the whole code, and every other position
+//! holds a bit of the variant index. Any two codewords differ in at least 4 bits, so one flipped
+//! bit can be corrected, and two flipped bits can be detected.
+
+/// the positions of the index bits, from lowest to highest
+fn data_positions(bits: u32) -> impl Iterator<Item = u32> {
+    (3..bits).filter(|pos| !pos.is_power_of_two())
+}
+
+/// the number of index bits in a `bits` bit code
+pub fn data_bits(bits: u32) -> u32 {
+    data_positions(bits).map(|_| 1).sum()
+}
+
+/// the number of codewords that fit in `bits` bits
+pub fn capacity(bits: u32) -> u128 {
+    1 << data_bits(bits)
+}
+
+/// the XOR of the positions of every set bit, excluding the overall parity bit
+fn syndrome(code: u128) -> u32 {
+    (1..u128::BITS)
+        .filter(|pos| code >> pos & 1 == 1)
+        .fold(0, |syndrome, pos| syndrome ^ pos)
+}
+
+pub fn encode(index: u128, bits: u32) -> Option<u128> {
+    if index.checked_shr(data_bits(bits)).unwrap_or(0) != 0 {
+        return None;
+    }
+
+    let mut code = data_positions(bits)
+        .enumerate()
+        .filter(|&(idx, _)| index >> idx & 1 == 1)
+        .fold(0_u128, |code, (_, pos)| code | 1 << pos);
+
+    // the check bits are at the power of two positions, so setting the bits of the syndrome
+    // cancels it out
+    let syndrome = syndrome(code);
+    for check in (0..u32::BITS)
+        .map(|bit| 1 << bit)
+        .take_while(|&pos| pos < bits)
+    {
+        if syndrome & check != 0 {
+            code |= 1 << check;
+        }
+    }
+
+    Some(code | u128::from(code.count_ones() % 2))
+}
+
+/// the index of `code`, or `None` if it is not a valid codeword
+pub fn decode(code: u128, bits: u32) -> Option<u128> {
+    if syndrome(code) != 0 || !code.count_ones().is_multiple_of(2) {
+        return None;
+    }
+
+    Some(
+        data_positions(bits)
+            .enumerate()
+            .filter(|&(_, pos)| code >> pos & 1 == 1)
+            .fold(0, |index, (idx, _)| index | 1 << idx),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{data_bits, decode, encode};
+
+    #[test]
+    pub fn data_bits_per_repr() {
+        assert_eq!(data_bits(8), 4);
+        assert_eq!(data_bits(16), 11);
+        assert_eq!(data_bits(32), 26);
+        assert_eq!(data_bits(64), 57);
+        assert_eq!(data_bits(128), 120);
+    }
+
+    #[test]
+    pub fn small_u8() {
+        let codes = (0..4).map(|index| encode(index, 8).unwrap());
+
+        assert!(codes.eq([0x00, 0x0f, 0x33, 0x3c]));
+        assert_eq!(encode(16, 8), None);
+    }
+
+    #[test]
+    pub fn round_trip() {
+        for bits in [8, 16, 64, 128] {
+            for index in [0, 1, 2, 0x0b, (1 << data_bits(bits)) - 1] {
+                let code = encode(index, bits).unwrap();
+                assert_eq!(decode(code, bits), Some(index));
+            }
+        }
+    }
+
+    #[test]
+    pub fn minimum_distance_u8() {
+        let codes = (0..16)
+            .map(|index| encode(index, 8).unwrap())
+            .collect::<Vec<_>>();
+        for (idx, a) in codes.iter().enumerate() {
+            for b in &codes[..idx] {
+                assert!((a ^ b).count_ones() >= 4, "{a:#x} {b:#x}");
+            }
+        }
+    }
+}
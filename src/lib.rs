@@ -19,7 +19,7 @@
 //! let mut serialized_val = postcard::to_allocvec(&val).unwrap();
 //!
 //! // *Random bit flip*
-//! serialized_val[0] |= 0x01;
+//! serialized_val[0] ^= 0x01;
 //!
 //! // This successfully deserializes, but is the incorrect value!
 //! let new_val: Foo = postcard::from_bytes(&serialized_val).unwrap();
@@ -40,12 +40,25 @@
 //! let mut serialized_val = postcard::to_allocvec(&val).unwrap();
 //!
 //! // *Random bit flip*
-//! serialized_val[0] |= 0x01;
+//! serialized_val[0] ^= 0x01;
 //!
 //! // This fails to deserialize
 //! let new_par_err: postcard::Result<Foo> = postcard::from_bytes(&serialized_val);
 //! assert_eq!(new_par_err, Err(postcard::Error::SerdeDeCustom));
 //! ```
+//!
+//! Without serde, `TryFrom` does the same, and tells a corrupted value apart from one that is
+//! just not a variant
+//! ```
+//! # use enum_parity::{bit_parity, ParityError};
+//! #[repr(u8)]
+//! #[bit_parity(even)]
+//! # #[derive(Debug, Eq, PartialEq)]
+//! enum Foo { A, B, C, D }
+//!
+//! let raw = Foo::A as u8 ^ 0x01;
+//! assert_eq!(Foo::try_from(raw), Err(ParityError::InvalidParity(raw)));
+//! ```
 //! # Examples
 //!
 //! ## Even Bit Parity
@@ -61,10 +74,10 @@
 //!     Quo,
 //! }
 //!
-//! assert_eq!(EvenSample::Foo as u8, 0x00);
-//! assert_eq!(EvenSample::Bar as u8, 0x03);
-//! assert_eq!(EvenSample::Baz as u8, 0x05);
-//! assert_eq!(EvenSample::Quo as u8, 0x06);
+//! assert_eq!(EvenSample::Foo as u8, 0x03);
+//! assert_eq!(EvenSample::Bar as u8, 0x05);
+//! assert_eq!(EvenSample::Baz as u8, 0x06);
+//! assert_eq!(EvenSample::Quo as u8, 0x09);
 //! ```
 //!
 //! ## Odd Bit Parity
@@ -86,248 +99,175 @@
 //! assert_eq!(OddSample::Sit as u8, 0x07);
 //! ```
 
-mod bit_par_iter;
-mod int_repr;
+#![no_std]
 
-use std::{collections::HashMap, fmt::Display, str::FromStr};
+use core::fmt::Display;
 
-use bit_par_iter::{BitParityIter, IntegerParity};
-use darling::{FromAttributes, FromMeta};
-use int_repr::IntRepr;
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::{Expr, ItemEnum, Variant, parse_macro_input, spanned::Spanned};
+#[doc(inline)]
+pub use enum_parity_macros::bit_parity;
 
-#[derive(Copy, Clone, Debug, FromMeta)]
-enum Parity {
-    Even,
-    Odd,
-}
+/// The error returned when a discriminant has more bit errors than can be corrected
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Uncorrectable;
 
-// TODO could probably get rid of this for some provided method from darling?
-impl Display for Parity {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Even => "even",
-                Self::Odd => "odd",
-            }
-        )
+impl Display for Uncorrectable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "discriminant has more bit errors than can be corrected")
     }
 }
 
-#[derive(Debug, Clone, FromMeta)]
-#[darling(derive_syn_parse)]
-struct BitParityArgs {
-    #[darling(flatten)]
-    parity: Parity,
-    #[darling(default)]
-    allow_explicit_overrides: bool,
-}
+impl core::error::Error for Uncorrectable {}
 
-struct Ctx {
-    repr: IntRepr,
-    parity: Parity,
-    allow_explicit_overrides: bool,
+/// The error returned when a raw value is converted to an enum with `TryFrom`, and it is not the
+/// discriminant of any variant
+///
+/// Either way, the raw value is kept.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParityError<T> {
+    /// The value failed the enum's parity check, so it was corrupted
+    InvalidParity(T),
+    /// The value passed the enum's parity check, but no variant has it as its discriminant
+    NoVariant(T),
 }
 
-fn parse_discriminant<N>(ctx: &Ctx, (_eq_tok, expr): (syn::token::Eq, Expr)) -> syn::Result<N>
-where
-    N: IntegerParity + darling::ToTokens + FromStr,
-    N::Err: Display,
-{
-    let Expr::Lit(syn::ExprLit {
-        lit: syn::Lit::Int(lit),
-        ..
-    }) = expr.clone()
-    else {
-        // the expression was not a valid literal
-        return Err(syn::Error::new(
-            expr.span(),
-            "Invalid or unsupported enum discriminant value. Only literals are allowed",
-        ));
-    };
-
-    let lit = lit.base10_parse::<N>()?;
-
-    if lit.has_parity(ctx.parity) || ctx.allow_explicit_overrides {
-        Ok(lit)
-    } else {
-        Err(syn::Error::new(
-            expr.span(),
-            format!(
-                "explicit discriminant does not have `{}` parity",
-                ctx.parity,
-            ),
-        ))
+impl<T: Copy> ParityError<T> {
+    /// The raw value that could not be converted
+    pub const fn raw(&self) -> T {
+        match self {
+            Self::InvalidParity(raw) | Self::NoVariant(raw) => *raw,
+        }
     }
 }
 
-fn next_discriminant<N>(
-    ctx: &Ctx,
-    bpi: &mut BitParityIter<N>,
-    variant: &Variant,
-    explicit_discriminants: &HashMap<N, Span>,
-) -> syn::Result<N>
-where
-    N: IntegerParity + Eq + std::hash::Hash,
-{
-    // TODO not a huge fan of the control flow in this function...
-    if let Some(next_val) = bpi.next() {
-        if let Some(span) = explicit_discriminants.get(&next_val) {
-            let mut err = syn::Error::new(*span, "previous assignment here");
-
-            err.combine(syn::Error::new(
-                variant.span(),
-                "discriminant value is already assigned",
-            ));
-
-            return Err(err);
+impl<T: Display> Display for ParityError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidParity(raw) => write!(f, "discriminant {raw} failed its parity check"),
+            Self::NoVariant(raw) => write!(
+                f,
+                "discriminant {raw} passed its parity check, but is not a variant"
+            ),
         }
-        return Ok(next_val);
     }
+}
+
+impl<T: core::fmt::Debug + Display> core::error::Error for ParityError<T> {}
 
-    // if we got out of the for loop without returning, then we ran out of discriminants
-    Err(syn::Error::new_spanned(
-        variant,
-        format!(
-            "ran out of discriminant values for `{}` repr type",
-            ctx.repr
-        ),
-    ))
+/// The variant nearest to a raw value, as returned by `decode_nearest`
+///
+/// The distance is the number of bits that differ between the raw value and a discriminant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Decoded<T: 'static> {
+    /// The raw value is the discriminant of this variant
+    Exact(T),
+    /// This variant is nearer than any other, once the bits in `flipped_mask` are flipped back
+    Corrected {
+        variant: T,
+        /// the bits that differ from the discriminant, zero extended from the repr
+        flipped_mask: u128,
+    },
+    /// Several variants are equally near, so the value cannot be corrected
+    Ambiguous { candidates: Candidates<T> },
 }
 
-fn generic_expand<T>(ctx: &Ctx, mut enum_item: ItemEnum) -> syn::Result<TokenStream>
-where
-    T: IntegerParity + darling::ToTokens + FromStr + Eq + std::hash::Hash + std::fmt::Debug + Ord,
-    T::Err: Display,
-{
-    // iterate through all the enum variants, and validate all the explicit discriminants
-    let mut explicit_discriminants = enum_item
-        .variants
-        .iter()
-        .filter_map(|variant| {
-            variant
-                .discriminant
-                .clone()
-                .map(|disc| parse_discriminant::<T>(ctx, disc).map(|val| (val, variant.span())))
-        })
-        .collect::<syn::Result<HashMap<T, Span>>>()?;
+/// The variants that are equally near to a raw value, in the bits that are known
+///
+/// These are borrowed from the enum's variants, so nothing is allocated.
+#[derive(PartialEq, Eq, Hash)]
+pub struct Candidates<T: 'static> {
+    variants: &'static [T],
+    /// the bits of each variant's discriminant, zero extended from the repr
+    discriminants: &'static [u128],
+    raw: u128,
+    /// the bits that are compared, which leaves out any erased ones
+    known: u128,
+    distance: u32,
+}
 
-    let mut bpi = BitParityIter::<T>::new(ctx.parity);
-    for variant in &mut enum_item.variants {
-        let next_disc = if let Some(disc) = variant.discriminant.clone() {
-            let next_disc = parse_discriminant(ctx, disc)?;
+impl<T> Candidates<T> {
+    #[doc(hidden)]
+    pub const fn new(
+        variants: &'static [T],
+        discriminants: &'static [u128],
+        raw: u128,
+        known: u128,
+        distance: u32,
+    ) -> Self {
+        Self {
+            variants,
+            discriminants,
+            raw,
+            known,
+            distance,
+        }
+    }
 
-            bpi.set_override(next_disc);
+    /// The number of known bits that differ between the raw value and each candidate
+    #[must_use]
+    pub const fn distance(&self) -> u32 {
+        self.distance
+    }
 
-            next_disc
-        } else {
-            let next_disc = next_discriminant(ctx, &mut bpi, variant, &explicit_discriminants)?;
+    /// Every candidate, in declaration order
+    pub fn iter(&self) -> impl Iterator<Item = &'static T> + use<T> {
+        let (raw, known, distance) = (self.raw, self.known, self.distance);
+        self.variants
+            .iter()
+            .zip(self.discriminants)
+            .filter(move |&(_, &discriminant)| {
+                ((discriminant ^ raw) & known).count_ones() == distance
+            })
+            .map(|(variant, _)| variant)
+    }
 
-            explicit_discriminants.insert(next_disc, variant.span());
-            next_disc
-        };
+    /// The number of candidates
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
 
-        variant.discriminant = Some((syn::token::Eq::default(), syn::parse_quote!(#next_disc)));
+    /// Whether there are no candidates, which only happens for an enum without variants
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
     }
+}
 
-    Ok(quote! {#enum_item})
+/// The error returned by `decode_with_erasures`, when the bits that are not erased do not single
+/// out a variant
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErasureError<T: 'static> {
+    /// No variant agrees with the bits that are not erased, so some of those are wrong as well
+    NoVariant,
+    /// Several variants agree with the bits that are not erased
+    Ambiguous(Candidates<T>),
 }
-fn specialize_expand(ctx: &Ctx, enum_item: ItemEnum) -> syn::Result<TokenStream> {
-    match ctx.repr {
-        IntRepr::U8 => generic_expand::<u8>(ctx, enum_item),
-        IntRepr::U16 => generic_expand::<u16>(ctx, enum_item),
-        IntRepr::U32 => generic_expand::<u32>(ctx, enum_item),
-        IntRepr::U64 => generic_expand::<u64>(ctx, enum_item),
-        IntRepr::U128 => generic_expand::<u128>(ctx, enum_item),
-        IntRepr::Usize => generic_expand::<usize>(ctx, enum_item),
-        IntRepr::I8 => generic_expand::<i8>(ctx, enum_item),
-        IntRepr::I16 => generic_expand::<i16>(ctx, enum_item),
-        IntRepr::I32 => generic_expand::<i32>(ctx, enum_item),
-        IntRepr::I64 => generic_expand::<i64>(ctx, enum_item),
-        IntRepr::I128 => generic_expand::<i128>(ctx, enum_item),
-        IntRepr::Isize => generic_expand::<isize>(ctx, enum_item),
+
+impl<T> Display for ErasureError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NoVariant => write!(f, "no variant agrees with the bits that are not erased"),
+            Self::Ambiguous(candidates) => write!(
+                f,
+                "{} variants agree with the bits that are not erased",
+                candidates.len()
+            ),
+        }
     }
 }
 
-fn try_expand(args: &BitParityArgs, enum_item: ItemEnum) -> syn::Result<TokenStream> {
-    let repr = IntRepr::from_attributes(&enum_item.attrs)?;
-    let ctx = Ctx {
-        repr,
-        parity: args.parity,
-        allow_explicit_overrides: args.allow_explicit_overrides,
-    };
-    specialize_expand(&ctx, enum_item)
+impl<T: core::fmt::Debug> core::error::Error for ErasureError<T> {}
+
+impl<T> Clone for Candidates<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-/// An attribute macro for enums that enforces discriminant bit parity
-///
-/// See the [crate-level](crate) docs for more examples.
-///
-/// # Macro Parameters
-/// - `even` enforces even bit parity
-/// - `odd` enforces odd bit parity
-/// - `allow_explicit_overrides` accepts a boolean. It causes an explicit enum discriminant that does not match the given bit partity to:
-///   - If `true`, successfully compile.
-///   - If `false`, fail to compile
-///
-///   `allow_explicit_overrides` is optional, and defaults to `false`.
-///
-/// # Examples
-///
-/// ## Simple Usage
-/// In order to use even parity for enum discriminants:
-/// ```skip
-/// #[bit_parity(even)]
-/// ```
-///
-/// In order to use odd parity for enum discriminants:
-/// ```skip
-/// #[bit_parity(odd)]
-/// ```
-///
-/// ## Explicit Discriminant Values
-/// By default, assigning a value to an enum discriminant that does not have the matching bit parity fails to compile
-/// ```compile_fail
-/// # use enum_parity::bit_parity;
-/// #[repr(u8)]
-/// #[bit_parity(even)]
-/// enum Foo {
-///   // this fails to compile, because `0x01` does not have even bit parity
-///   A = 0x01,
-///   B,
-///   C,
-/// }
-/// ```
-///
-/// If you want to allow explicit discriminants that do not match the given bit parity, add `allow_explicit_overrides`
-/// ```
-/// # use enum_parity::bit_parity;
-/// #[repr(u8)]
-/// #[bit_parity(even, allow_explicit_overrides = true)]
-/// enum Foo {
-///   // `0x01` does not have even bit parity, but it is allowed from the `allow_explicit_overrides` parameter
-///   A = 0x01,
-///   B,
-///   C,
-/// }
-///
-/// assert_eq!(Foo::A as u8, 0x01);
-/// // `B` and `C` will have even bit parity
-/// assert_eq!(Foo::B as u8, 0x03);
-/// assert_eq!(Foo::C as u8, 0x05);
-/// ```
-///
-#[proc_macro_attribute]
-pub fn bit_parity(
-    args: proc_macro::TokenStream,
-    input: proc_macro::TokenStream,
-) -> proc_macro::TokenStream {
-    let args = parse_macro_input!(args as BitParityArgs);
-    let enum_item = parse_macro_input!(input as ItemEnum);
+impl<T> Copy for Candidates<T> {}
 
-    try_expand(&args, enum_item).map_or_else(|e| e.into_compile_error().into(), Into::into)
+impl<T: core::fmt::Debug> core::fmt::Debug for Candidates<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
 }
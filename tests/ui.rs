@@ -1,4 +1,5 @@
 #[test]
+#[allow(clippy::too_many_lines, reason = "one line per test case")]
 fn ui() {
     let t = trybuild::TestCases::new();
 
@@ -20,6 +21,142 @@ fn ui() {
     // odd parity with discriminants
     t.compile_fail("tests/ui/odd_with_even_no_override.rs");
 
+    // stuck-at values
+    t.pass("tests/ui/stuck_ok.rs");
+    t.compile_fail("tests/ui/stuck_explicit_err.rs");
+    t.compile_fail("tests/ui/stuck_overflow_err.rs");
+
+    // parity per byte or nibble
+    t.pass("tests/ui/granularity_ok.rs");
+    t.compile_fail("tests/ui/granularity_explicit_err.rs");
+    t.compile_fail("tests/ui/granularity_overflow_err.rs");
+    t.compile_fail("tests/ui/granularity_scheme_err.rs");
+
+    // fixed parity bit layout
+    t.pass("tests/ui/layout_ok.rs");
+    t.compile_fail("tests/ui/layout_explicit_err.rs");
+    t.compile_fail("tests/ui/layout_scheme_err.rs");
+    t.compile_fail("tests/ui/layout_stuck_err.rs");
+    t.compile_fail("tests/ui/layout_usize_err.rs");
+
+    // constant weight
+    t.pass("tests/ui/weight_ok.rs");
+    t.compile_fail("tests/ui/weight_explicit_err.rs");
+    t.compile_fail("tests/ui/weight_overflow_err.rs");
+
+    // berger codes
+    t.pass("tests/ui/berger_ok.rs");
+    t.compile_fail("tests/ui/berger_explicit_err.rs");
+    t.compile_fail("tests/ui/berger_overflow_err.rs");
+
+    // crc protected discriminants
+    t.pass("tests/ui/crc_ok.rs");
+    t.compile_fail("tests/ui/crc_explicit_err.rs");
+    t.compile_fail("tests/ui/crc_preset_err.rs");
+    t.compile_fail("tests/ui/crc_overflow_err.rs");
+    t.compile_fail("tests/ui/crc_width_err.rs");
+
+    // extended hamming codes
+    t.pass("tests/ui/secded_ok.rs");
+    t.compile_fail("tests/ui/secded_explicit_err.rs");
+    t.compile_fail("tests/ui/secded_override_err.rs");
+
+    // bch codes
+    t.pass("tests/ui/bch_ok.rs");
+    t.compile_fail("tests/ui/bch_explicit_err.rs");
+    t.compile_fail("tests/ui/bch_override_err.rs");
+    t.compile_fail("tests/ui/bch_overflow_err.rs");
+    t.compile_fail("tests/ui/bch_zero_err.rs");
+
+    // reed-solomon symbol distance
+    t.pass("tests/ui/symbol_ok.rs");
+    t.compile_fail("tests/ui/symbol_explicit_err.rs");
+    t.compile_fail("tests/ui/symbol_override_err.rs");
+    t.compile_fail("tests/ui/symbol_bits_err.rs");
+    t.compile_fail("tests/ui/symbol_overflow_err.rs");
+    t.compile_fail("tests/ui/symbol_scheme_err.rs");
+
+    // parity-check matrices
+    t.pass("tests/ui/check_matrix_ok.rs");
+    t.compile_fail("tests/ui/check_matrix_explicit_err.rs");
+    t.compile_fail("tests/ui/check_matrix_width_err.rs");
+    t.compile_fail("tests/ui/check_matrix_overflow_err.rs");
+
+    // gray codes
+    t.pass("tests/ui/gray_ok.rs");
+    t.compile_fail("tests/ui/gray_explicit_err.rs");
+    t.compile_fail("tests/ui/gray_wrap_odd_err.rs");
+    t.compile_fail("tests/ui/gray_stuck_err.rs");
+
+    // balanced codes
+    t.pass("tests/ui/balanced_ok.rs");
+    t.compile_fail("tests/ui/balanced_explicit_err.rs");
+    t.compile_fail("tests/ui/balanced_overflow_err.rs");
+
+    // burst error detection
+    t.pass("tests/ui/burst_ok.rs");
+    t.compile_fail("tests/ui/burst_explicit_err.rs");
+    t.compile_fail("tests/ui/burst_override_err.rs");
+    t.compile_fail("tests/ui/burst_overflow_err.rs");
+
+    // complement pairs
+    t.pass("tests/ui/complement_ok.rs");
+    t.compile_fail("tests/ui/complement_explicit_err.rs");
+    t.compile_fail("tests/ui/complement_repr_err.rs");
+    t.compile_fail("tests/ui/complement_usize_err.rs");
+
+    // decimal check digits
+    t.pass("tests/ui/check_digit_ok.rs");
+    t.compile_fail("tests/ui/check_digit_explicit_err.rs");
+    t.compile_fail("tests/ui/check_digit_override_err.rs");
+    t.compile_fail("tests/ui/check_digit_overflow_err.rs");
+    t.compile_fail("tests/ui/check_digit_algorithm_err.rs");
+
+    // distance between specific variants
+    t.pass("tests/ui/constraint_ok.rs");
+    t.compile_fail("tests/ui/constraint_explicit_err.rs");
+    t.compile_fail("tests/ui/constraint_unsatisfiable_err.rs");
+    t.compile_fail("tests/ui/constraint_too_far_err.rs");
+    t.compile_fail("tests/ui/constraint_unknown_variant_err.rs");
+
+    // minimum hamming distance
+    t.pass("tests/ui/min_distance_ok.rs");
+    t.compile_fail("tests/ui/min_distance_overflow_err.rs");
+    t.compile_fail("tests/ui/min_distance_budget_err.rs");
+    t.compile_fail("tests/ui/min_distance_explicit_err.rs");
+    t.pass("tests/ui/maximize_distance_ok.rs");
+    t.compile_fail("tests/ui/maximize_distance_err.rs");
+
+    // discriminants from hashed variant names
+    t.pass("tests/ui/name_hash_ok.rs");
+    t.compile_fail("tests/ui/name_hash_scheme_err.rs");
+    t.compile_fail("tests/ui/name_hash_key_err.rs");
+    t.compile_fail("tests/ui/name_hash_overflow_err.rs");
+
+    // per enum tag bits
+    t.pass("tests/ui/tag_ok.rs");
+    t.compile_fail("tests/ui/tag_explicit_err.rs");
+    t.compile_fail("tests/ui/tag_overflow_err.rs");
+    t.compile_fail("tests/ui/tag_scheme_err.rs");
+    t.compile_fail("tests/ui/tag_fit_err.rs");
+    t.compile_fail("tests/ui/tag_repr_err.rs");
+    t.compile_fail("tests/ui/tag_usize_err.rs");
+
+    // validated conversion from the repr
+    t.pass("tests/ui/try_from_ok.rs");
+
+    // decoding and lookup tables in const contexts
+    t.pass("tests/ui/repr_consts_ok.rs");
+
+    // nearest variant decoding, with ambiguity
+    t.pass("tests/ui/decode_nearest_ok.rs");
+
+    // decoding around erased bits
+    t.pass("tests/ui/erasures_ok.rs");
+
+    // soft decision decoding
+    t.pass("tests/ui/soft_ok.rs");
+
     // using a const for an enum discriminant
     t.compile_fail("tests/ui/const_discrimin.rs");
 
@@ -31,6 +168,7 @@ fn ui() {
     t.compile_fail("tests/ui/repr_c.rs");
     t.compile_fail("tests/ui/missing_repr.rs");
     t.compile_fail("tests/ui/bad_arg_err.rs");
+    t.compile_fail("tests/ui/missing_scheme_err.rs");
     t.compile_fail("tests/ui/overflow_err.rs");
 
     // on other items
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(balanced(manchester))]
+enum NotManchester {
+    A,
+    // balanced, but `0b11` and `0b00` are not valid pairs
+    B = 0x0f,
+}
+
+fn main() {}
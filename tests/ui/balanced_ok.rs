@@ -0,0 +1,53 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(balanced)]
+enum BalancedU8 {
+    A,
+    B,
+    C = 0x3c,
+    D,
+}
+
+#[repr(u128)]
+#[bit_parity(balanced)]
+enum BalancedU128 {
+    A,
+    B,
+}
+
+#[repr(u16)]
+#[bit_parity(balanced(manchester))]
+enum ManchesterU16 {
+    A,
+    B,
+    C,
+    D = 0x5595,
+    E,
+}
+
+#[repr(i32)]
+#[bit_parity(balanced(manchester))]
+enum ManchesterI32 {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(BalancedU8::A as u8, 0x0f);
+    assert_eq!(BalancedU8::B as u8, 0x17);
+    assert_eq!(BalancedU8::C as u8, 0x3c);
+    assert_eq!(BalancedU8::D as u8, 0x47);
+
+    assert_eq!(BalancedU128::A as u128, u64::MAX as u128);
+    assert_eq!(BalancedU128::B as u128, 0x1_7fff_ffff_ffff_ffff);
+
+    assert_eq!(ManchesterU16::A as u16, 0x5555);
+    assert_eq!(ManchesterU16::B as u16, 0x5556);
+    assert_eq!(ManchesterU16::C as u16, 0x5559);
+    assert_eq!(ManchesterU16::D as u16, 0x5595);
+    assert_eq!(ManchesterU16::E as u16, 0x5596);
+
+    assert_eq!(ManchesterI32::A as i32, 0x5555_5555);
+    assert_eq!(ManchesterI32::B as i32, 0x5555_5556);
+}
@@ -0,0 +1,26 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(balanced(manchester))]
+enum TooBig {
+    Aa,
+    Ab,
+    Ac,
+    Ad,
+    Ae,
+    Af,
+    Ag,
+    Ah,
+    Ai,
+    Aj,
+    Ak,
+    Al,
+    Am,
+    An,
+    Ao,
+    Ap,
+    // 16 -- Too Many for 4 index bits
+    Aq,
+}
+
+fn main() {}
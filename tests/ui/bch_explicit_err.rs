@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u32)]
+#[bit_parity(bch(t = 2))]
+enum Link {
+    Idle,
+    Sync = 0x9ba,
+    Data,
+}
+
+fn main() {}
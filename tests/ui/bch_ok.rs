@@ -0,0 +1,46 @@
+use enum_parity::{Uncorrectable, bit_parity};
+
+#[repr(u32)]
+#[bit_parity(bch(t = 2))]
+#[derive(Debug, Eq, PartialEq)]
+enum Link {
+    Idle,
+    Sync,
+    Data,
+    Reset,
+}
+
+#[repr(u64)]
+#[bit_parity(bch(t = 3))]
+#[derive(Debug, Eq, PartialEq)]
+pub enum Wide {
+    A,
+    B,
+    #[cfg(any())]
+    Skipped,
+    C,
+}
+
+fn main() {
+    assert_eq!(Link::Idle as u32, 0x769);
+    assert_eq!(Link::Sync as u32, 0x9bb);
+    assert_eq!(Link::Data as u32, 0xed2);
+    assert_eq!(Link::Reset as u32, 0x1376);
+
+    for a in 0..32 {
+        assert_eq!(Link::correct(0xed2 ^ 1 << a), Ok(Link::Data));
+        for b in 0..a {
+            assert_eq!(Link::correct(0xed2 ^ 1 << a ^ 1 << b), Ok(Link::Data));
+        }
+    }
+    // three flipped bits are more than `t`
+    assert_eq!(Link::correct(0xed2 ^ 0b111), Err(Uncorrectable));
+    // a valid codeword, that is not assigned to a variant because it is all zeros
+    assert_eq!(Link::correct(0x00), Err(Uncorrectable));
+
+    let corrupted = Wide::C as u64 ^ 0x8000_0000_0000_0401;
+    assert_eq!(Wide::correct(corrupted), Ok(Wide::C));
+
+    const CORRECTED: Result<Link, Uncorrectable> = Link::correct(0x9bb ^ 0x0003);
+    assert_eq!(CORRECTED, Ok(Link::Sync));
+}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(bch(t = 2))]
+enum Link {
+    Idle,
+    Sync,
+}
+
+fn main() {}
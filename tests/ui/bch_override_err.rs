@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u32)]
+#[bit_parity(bch(t = 2), allow_explicit_overrides = true)]
+enum Link {
+    Idle,
+    Sync = 0x76e,
+    Data,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u32)]
+#[bit_parity(bch(t = 0))]
+enum Link {
+    Idle,
+    Sync,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(berger)]
+enum NotBerger {
+    A,
+    B = 0x03,
+    C,
+}
+
+fn main() {}
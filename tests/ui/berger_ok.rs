@@ -0,0 +1,42 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(berger)]
+enum BergerU8 {
+    A,
+    B,
+    C,
+    D,
+    E = 0x6c,
+    F,
+}
+
+#[repr(i32)]
+#[bit_parity(berger)]
+enum BergerI32 {
+    A,
+    B,
+}
+
+#[repr(u128)]
+#[bit_parity(berger)]
+enum BergerU128 {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(BergerU8::A as u8, 0xa0);
+    assert_eq!(BergerU8::B as u8, 0x81);
+    assert_eq!(BergerU8::C as u8, 0x82);
+    assert_eq!(BergerU8::D as u8, 0x63);
+    assert_eq!(BergerU8::E as u8, 0x6c);
+    assert_eq!(BergerU8::F as u8, 0x4d);
+
+    // 27 data bits, so 27 zeros in the top 5 bits
+    assert_eq!(BergerI32::A as i32, 27 << 27);
+    assert_eq!(BergerI32::B as i32, 26 << 27 | 1);
+
+    assert_eq!(BergerU128::A as u128, 121 << 121);
+    assert_eq!(BergerU128::B as u128, 120 << 121 | 1);
+}
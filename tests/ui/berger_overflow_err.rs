@@ -0,0 +1,42 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(berger)]
+enum TooBig {
+    Aa,
+    Ab,
+    Ac,
+    Ad,
+    Ae,
+    Af,
+    Ag,
+    Ah,
+    Ai,
+    Aj,
+    Ak,
+    Al,
+    Am,
+    An,
+    Ao,
+    Ap,
+    Aq,
+    Ar,
+    As,
+    At,
+    Au,
+    Av,
+    Aw,
+    Ax,
+    Ay,
+    Az,
+    Ba,
+    Bb,
+    Bc,
+    Bd,
+    Be,
+    Bf,
+    // 32 -- Too Many for a berger code in a u8 repr
+    Bg,
+}
+
+fn main() {}
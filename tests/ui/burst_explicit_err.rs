@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(burst = 3)]
+enum Burst3 {
+    A,
+    B = 0b00010_011,
+}
+
+fn main() {}
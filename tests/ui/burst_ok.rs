@@ -0,0 +1,38 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(burst = 3)]
+enum Burst3 {
+    A,
+    B,
+    C,
+}
+
+#[repr(u32)]
+#[bit_parity(burst = 8)]
+enum Burst8 {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(burst = 3, allow_explicit_overrides = true)]
+enum Overridden {
+    A,
+    // not a codeword, but still more than a 3 bit burst away from every other variant
+    B = 0xe0,
+    C,
+}
+
+fn main() {
+    assert_eq!(Burst3::A as u8, 0b00001_001);
+    assert_eq!(Burst3::B as u8, 0b00010_010);
+    assert_eq!(Burst3::C as u8, 0b00011_011);
+
+    assert_eq!(Burst8::A as u32, 0x0101);
+    assert_eq!(Burst8::B as u32, 0x0202);
+
+    assert_eq!(Overridden::A as u8, 0x09);
+    assert_eq!(Overridden::B as u8, 0xe0);
+    assert_eq!(Overridden::C as u8, 0x12);
+}
@@ -0,0 +1,17 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(burst = 5)]
+enum TooBig {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    // 7 -- Too Many for 3 index bits, since index 0 is all zeros
+    H,
+}
+
+fn main() {}
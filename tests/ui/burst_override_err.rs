@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(burst = 3, allow_explicit_overrides = true)]
+enum Overridden {
+    A,
+    // `A` is `0x09`, so a 2 bit burst turns one into the other
+    B = 0x0f,
+}
+
+fn main() {}
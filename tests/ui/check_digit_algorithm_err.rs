@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(check_digit = "luhn")]
+enum Fault {
+    Overheat,
+    Undervolt,
+}
+
+fn main() {}
@@ -0,0 +1,51 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(check_digit = "damm")]
+#[derive(Debug, Eq, PartialEq)]
+enum Fault {
+    Overheat,
+    Undervolt,
+    #[cfg(any())]
+    Skipped,
+    Jammed,
+    Timeout = 5724,
+}
+
+#[repr(i8)]
+#[bit_parity(check_digit = "verhoeff")]
+#[derive(Debug, Eq, PartialEq)]
+pub enum Status {
+    Ok,
+    Busy,
+    Failed,
+}
+
+fn main() {
+    assert_eq!(Fault::Overheat as u16, 13);
+    assert_eq!(Fault::Undervolt as u16, 21);
+    assert_eq!(Fault::Jammed as u16, 45);
+    assert_eq!(Fault::Timeout as u16, 5724);
+
+    assert_eq!(Fault::from_decimal("5724"), Some(Fault::Timeout));
+    assert_eq!(Fault::from_decimal("005724"), Some(Fault::Timeout));
+    // a wrong digit, and two swapped digits
+    assert_eq!(Fault::from_decimal("5824"), None);
+    assert_eq!(Fault::from_decimal("5742"), None);
+    // not a number, or too large for the repr
+    assert_eq!(Fault::from_decimal(""), None);
+    assert_eq!(Fault::from_decimal("57 24"), None);
+    assert_eq!(Fault::from_decimal("+5724"), None);
+    assert_eq!(Fault::from_decimal("6553500"), None);
+    // a valid check digit, but not a variant
+    assert_eq!(Fault::from_decimal("37"), None);
+
+    assert_eq!(Status::Ok as i8, 15);
+    assert_eq!(Status::Busy as i8, 27);
+    assert_eq!(Status::Failed as i8, 36);
+    assert_eq!(Status::from_decimal("27"), Some(Status::Busy));
+    assert_eq!(Status::from_decimal("72"), None);
+
+    const PARSED: Option<Fault> = Fault::from_decimal("21");
+    assert_eq!(PARSED, Some(Fault::Undervolt));
+}
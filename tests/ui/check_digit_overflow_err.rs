@@ -0,0 +1,21 @@
+use enum_parity::bit_parity;
+
+#[repr(i8)]
+#[bit_parity(check_digit = "damm")]
+enum Fault {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+}
+
+fn main() {}
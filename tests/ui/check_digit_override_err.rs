@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(check_digit = "verhoeff", allow_explicit_overrides = true)]
+enum Fault {
+    Overheat,
+    Timeout = 5724,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(check_matrix = [0b0101_0101, 0b0110_0110, 0b0111_1000, 0b1111_1111])]
+enum Hamming {
+    A,
+    B = 0x4c,
+}
+
+fn main() {}
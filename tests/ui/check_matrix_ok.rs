@@ -0,0 +1,55 @@
+use enum_parity::bit_parity;
+
+// the extended (8, 4) Hamming code
+#[repr(u8)]
+#[bit_parity(check_matrix = [0b0101_0101, 0b0110_0110, 0b0111_1000, 0b1111_1111])]
+enum Hamming {
+    A,
+    B,
+    C = 0x4b,
+    D,
+}
+
+// even parity in each half
+#[repr(u32)]
+#[bit_parity(check_matrix = [0x0000_ffff, 0xffff_0000])]
+enum Halves {
+    A,
+    B,
+}
+
+// the extended (64, 51) BCH code, with too many codewords to weigh one by one
+#[repr(u64)]
+#[bit_parity(check_matrix = [
+    0x0009_e68c_6fc9_3ac5,
+    0x0013_cd18_df92_758a,
+    0x0027_9a31_bf24_eb14,
+    0x0046_d2ef_1180_eced,
+    0x0084_4352_4cc8_e31f,
+    0x0101_6028_f658_fcfb,
+    0x0202_c051_ecb1_f9f6,
+    0x0405_80a3_d963_f3ec,
+    0x0802_e7cb_dd0e_dd1d,
+    0x1005_cf97_ba1d_ba3a,
+    0x2002_79a3_1bf2_4eb1,
+    0x4004_f346_37e4_9d62,
+    0x8007_5d84_2547_1643,
+])]
+enum ExtendedBch {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(Hamming::A as u8, 0x1e);
+    assert_eq!(Hamming::B as u8, 0x2d);
+    assert_eq!(Hamming::C as u8, 0x4b);
+    assert_eq!(Hamming::D as u8, 0x55);
+    assert_eq!(Hamming::MIN_DISTANCE, 4);
+
+    assert_eq!(Halves::A as u32, 0x03);
+    assert_eq!(Halves::B as u32, 0x05);
+    assert_eq!(Halves::MIN_DISTANCE, 2);
+
+    assert_eq!(ExtendedBch::MIN_DISTANCE, 6);
+}
@@ -0,0 +1,12 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(check_matrix = [0x01, 0x02, 0x04, 0x08, 0x10, 0x20])]
+enum Tight {
+    A,
+    B,
+    C,
+    D,
+}
+
+fn main() {}
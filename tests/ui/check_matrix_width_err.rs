@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(check_matrix = [0b0101_0101, 0x1_0000])]
+enum Hamming {
+    A,
+    B,
+}
+
+fn main() {}
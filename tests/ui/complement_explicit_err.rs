@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(complement)]
+enum SafetyState {
+    Init,
+    Safe = 0xa55b,
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(complement)]
+enum SafetyState {
+    Init,
+    Safe,
+    Armed = 0xa55a,
+    Fault,
+}
+
+#[repr(u32)]
+#[bit_parity(complement)]
+enum Wide {
+    A,
+    B,
+}
+
+#[repr(i64)]
+#[bit_parity(complement)]
+enum Signed {
+    A,
+    B,
+}
+
+fn main() {
+    // the index in the low half, and its complement in the high half
+    assert_eq!(SafetyState::Init as u16, 0xff00);
+    assert_eq!(SafetyState::Safe as u16, 0xfe01);
+    assert_eq!(SafetyState::Armed as u16, 0xa55a);
+    assert_eq!(SafetyState::Fault as u16, 0xa45b);
+
+    assert_eq!(Wide::A as u32, 0xffff_0000);
+    assert_eq!(Wide::B as u32, 0xfffe_0001);
+
+    assert_eq!(Signed::A as i64 as u64, 0xffff_ffff_0000_0000);
+    assert_eq!(Signed::B as i64 as u64, 0xffff_fffe_0000_0001);
+}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(complement)]
+enum SafetyState {
+    Init,
+    Safe,
+}
+
+fn main() {}
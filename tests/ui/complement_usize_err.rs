@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+// the halves are split at a different bit on every pointer width
+#[repr(usize)]
+#[bit_parity(complement)]
+enum SafetyState {
+    Init,
+    Safe,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum Interlock {
+    Safe = 0x03,
+    Idle,
+    #[parity(distance_from(Safe) >= 6)]
+    Armed = 0x0f,
+}
+
+fn main() {}
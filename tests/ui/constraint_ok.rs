@@ -0,0 +1,41 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum Interlock {
+    Safe,
+    Idle,
+    #[parity(distance_from(Safe) >= 6)]
+    Armed,
+}
+
+#[repr(u8)]
+#[bit_parity(odd)]
+enum Later {
+    #[parity(distance_from(Safe) >= 7)]
+    Armed,
+    Safe = 0xf1,
+}
+
+#[repr(u16)]
+#[bit_parity(even)]
+enum Several {
+    Safe,
+    Open,
+    #[parity(distance_from(Safe) >= 4, distance_from(Open) >= 4)]
+    Fire,
+}
+
+fn main() {
+    assert_eq!(Interlock::Safe as u8, 0x03);
+    assert_eq!(Interlock::Idle as u8, 0x05);
+    assert_eq!(Interlock::Armed as u8, 0x3c);
+
+    // a later explicit discriminant is kept away from as well
+    assert_eq!(Later::Armed as u8, 0x0e);
+    assert_eq!(Later::Safe as u8, 0xf1);
+
+    assert_eq!(Several::Safe as u16, 0x03);
+    assert_eq!(Several::Open as u16, 0x05);
+    assert_eq!(Several::Fire as u16, 0x18);
+}
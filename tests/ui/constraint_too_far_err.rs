@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum Interlock {
+    Safe,
+    #[parity(distance_from(Safe) >= 9)]
+    Armed,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum Interlock {
+    Safe = 0xf0,
+    // only 0x0f is far enough, but values are assigned after the previous one
+    #[parity(distance_from(Safe) >= 8)]
+    Armed,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(crc = "crc4-itu")]
+enum BadChecksum {
+    A,
+    B = 0x14,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(crc = "crc4-itu")]
+enum Crc4 {
+    A,
+    B,
+    C,
+    D = 0xb2,
+    E,
+}
+
+#[repr(u16)]
+#[bit_parity(crc(poly = 0x13, width = 4))]
+enum CustomCrc4 {
+    A,
+    B,
+    C,
+}
+
+#[repr(i32)]
+#[bit_parity(crc = "crc8-smbus")]
+enum Crc8 {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(Crc4::A as u8, 0x1d);
+    assert_eq!(Crc4::B as u8, 0x23);
+    assert_eq!(Crc4::C as u8, 0x3e);
+    assert_eq!(Crc4::D as u8, 0xb2);
+    assert_eq!(Crc4::E as u8, 0xca);
+
+    assert_eq!(CustomCrc4::A as u16, 0x13);
+    assert_eq!(CustomCrc4::B as u16, 0x26);
+    assert_eq!(CustomCrc4::C as u16, 0x35);
+
+    assert_eq!(Crc8::A as i32, 0x107);
+    assert_eq!(Crc8::B as i32, 0x20e);
+}
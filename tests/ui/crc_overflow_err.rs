@@ -0,0 +1,13 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(crc(poly = 0x07, width = 6))]
+enum TooBig {
+    A,
+    B,
+    C,
+    // 3 -- Too Many for 2 index bits, since index 0 is all zeros
+    D,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(crc = "crc4-foo")]
+enum UnknownCrc {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(crc(poly = 0x07, width = 8))]
+enum NoIndexBits {
+    A,
+    B,
+}
+
+fn main() {}
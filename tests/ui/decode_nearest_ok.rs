@@ -0,0 +1,86 @@
+use enum_parity::{Decoded, bit_parity};
+
+#[repr(u8)]
+#[bit_parity(even)]
+#[derive(Debug, Eq, PartialEq)]
+enum Even {
+    A,
+    B,
+    #[cfg(any())]
+    Skipped,
+    C,
+    D,
+}
+
+#[repr(i8)]
+#[bit_parity(secded)]
+#[derive(Debug, Eq, PartialEq)]
+enum Secded {
+    A,
+    B,
+}
+
+#[repr(u16)]
+#[bit_parity(min_distance = 5)]
+#[derive(Debug, Eq, PartialEq)]
+enum Distance {
+    A,
+    B,
+    C,
+}
+
+const NEAREST: Decoded<Even> = Even::decode_nearest(0x09);
+
+/// the candidates of an ambiguous value, or `None` if it was decoded
+fn candidates<T: 'static>(decoded: Decoded<T>) -> Option<Vec<&'static T>> {
+    match decoded {
+        Decoded::Ambiguous { candidates } => Some(candidates.iter().collect()),
+        Decoded::Exact(_) | Decoded::Corrected { .. } => None,
+    }
+}
+
+fn main() {
+    assert_eq!(NEAREST, Decoded::Exact(Even::C));
+    assert_eq!(Even::decode_nearest(0x03), Decoded::Exact(Even::A));
+    // one flip away from even parity is one flip away from several variants
+    assert_eq!(
+        candidates(Even::decode_nearest(0x01)),
+        Some(vec![&Even::A, &Even::B, &Even::C])
+    );
+    assert_eq!(
+        candidates(Even::decode_nearest(0x0b)),
+        Some(vec![&Even::A, &Even::C, &Even::D])
+    );
+    assert_eq!(
+        Even::decode_nearest(0x19),
+        Decoded::Corrected {
+            variant: Even::C,
+            flipped_mask: 0x10,
+        }
+    );
+    let Decoded::Ambiguous { candidates } = Even::decode_nearest(0xff) else {
+        panic!("expected ambiguous");
+    };
+    assert_eq!((candidates.len(), candidates.distance()), (4, 6));
+    assert_eq!(format!("{candidates:?}"), "[A, B, C, D]");
+
+    // the mask is zero extended, even from a signed repr
+    assert_eq!(
+        Secded::decode_nearest(Secded::B as i8 ^ i8::MIN),
+        Decoded::Corrected {
+            variant: Secded::B,
+            flipped_mask: 0x80,
+        }
+    );
+
+    for (variant, raw) in [(Distance::A, Distance::A as u16), (Distance::C, Distance::C as u16)] {
+        assert_eq!(
+            Distance::decode_nearest(raw ^ 0x0101),
+            Decoded::Corrected {
+                variant,
+                flipped_mask: 0x0101,
+            }
+        );
+    }
+    let _ = Distance::B;
+}
@@ -0,0 +1,53 @@
+use enum_parity::{ErasureError, bit_parity};
+
+#[repr(u8)]
+#[bit_parity(even)]
+#[derive(Debug, Eq, PartialEq)]
+enum Even {
+    A,
+    B,
+    #[cfg(any())]
+    Skipped,
+    C,
+    D,
+}
+
+#[repr(i8)]
+#[bit_parity(secded)]
+#[derive(Debug, Eq, PartialEq)]
+enum Secded {
+    A,
+    B,
+    C,
+}
+
+const ERASED: Result<Even, ErasureError<Even>> = Even::decode_with_erasures(0x01, 0x02);
+
+fn main() {
+    // even parity fills in one erased bit
+    assert_eq!(ERASED, Ok(Even::A));
+    assert_eq!(Even::decode_with_erasures(0x09, 0x08), Ok(Even::C));
+    assert_eq!(Even::decode_with_erasures(0x0a, 0x00), Ok(Even::D));
+    // a flipped bit that is not erased is caught
+    assert_eq!(Even::decode_with_erasures(0x10, 0x01), Err(ErasureError::NoVariant));
+
+    let Err(ErasureError::Ambiguous(candidates)) = Even::decode_with_erasures(0x00, 0x0f) else {
+        panic!("expected ambiguous");
+    };
+    assert_eq!(candidates.iter().collect::<Vec<_>>(), [&Even::A, &Even::B, &Even::C, &Even::D]);
+    assert_eq!(candidates.distance(), 0);
+    assert_eq!(
+        ErasureError::Ambiguous(candidates).to_string(),
+        "4 variants agree with the bits that are not erased"
+    );
+    let Err(ErasureError::Ambiguous(candidates)) = Even::decode_with_erasures(0x01, 0x06) else {
+        panic!("expected ambiguous");
+    };
+    assert_eq!(candidates.iter().collect::<Vec<_>>(), [&Even::A, &Even::B]);
+
+    // a minimum distance of 4 fills in any 3 erased bits, including the sign bit
+    for erased in [0x43_u8 as i8, 0x0e, i8::MIN | 0x01] {
+        assert_eq!(Secded::decode_with_erasures(Secded::C as i8 ^ erased, erased), Ok(Secded::C));
+    }
+    let _ = (Secded::A, Secded::B);
+}
@@ -4,15 +4,15 @@ use enum_parity::bit_parity;
 #[repr(u64)]
 enum EvenEnum {
     A,
-    // skipping 3
-    B = 0x05,
+    // skipping 5
+    B = 0x06,
     C,
     D,
 }
 
 fn main() {
-    assert_eq!(EvenEnum::A as u64, 0);
-    assert_eq!(EvenEnum::B as u64, 5);
-    assert_eq!(EvenEnum::C as u64, 6);
-    assert_eq!(EvenEnum::D as u64, 9);
+    assert_eq!(EvenEnum::A as u64, 3);
+    assert_eq!(EvenEnum::B as u64, 6);
+    assert_eq!(EvenEnum::C as u64, 9);
+    assert_eq!(EvenEnum::D as u64, 10);
 }
@@ -10,7 +10,7 @@ enum EvenEnum {
 }
 
 fn main() {
-    assert_eq!(EvenEnum::A as u64, 0);
+    assert_eq!(EvenEnum::A as u64, 3);
     assert_eq!(EvenEnum::B as u64, 4);
     assert_eq!(EvenEnum::C as u64, 5);
     assert_eq!(EvenEnum::D as u64, 6);
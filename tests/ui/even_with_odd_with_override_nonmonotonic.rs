@@ -1,6 +1,6 @@
 use enum_parity::bit_parity;
 
-#[bit_parity(even, allow_explicit_overrides = true)]
+#[bit_parity(even, allow_explicit_overrides = true, exclude_stuck = false)]
 #[repr(u64)]
 enum EvenEnum {
     A, // 0x00
@@ -1,7 +1,7 @@
 use enum_parity::bit_parity;
 
 #[repr(u8)]
-#[bit_parity(even)]
+#[bit_parity(even, exclude_stuck = false)]
 enum BadEnum {
     A = 0,
     B,
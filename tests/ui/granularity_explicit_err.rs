@@ -0,0 +1,12 @@
+use enum_parity::bit_parity;
+
+#[repr(u32)]
+#[bit_parity(even, granularity = "byte")]
+enum EvenBytes {
+    A,
+    // even parity over the whole word, but not in bytes 1 and 2
+    B = 0x0001_0103,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,40 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(even, granularity = "byte")]
+enum EvenBytes {
+    A,
+    B,
+    C,
+    // the next value once the low byte runs out
+    D = 0x0300,
+    E,
+}
+
+#[repr(u32)]
+#[bit_parity(odd, granularity = "nibble")]
+enum OddNibbles {
+    A,
+    B,
+}
+
+#[repr(i8)]
+#[bit_parity(even, granularity = "word")]
+enum EvenWord {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(EvenBytes::A as u16, 0x0003);
+    assert_eq!(EvenBytes::B as u16, 0x0005);
+    assert_eq!(EvenBytes::C as u16, 0x0006);
+    assert_eq!(EvenBytes::D as u16, 0x0300);
+    assert_eq!(EvenBytes::E as u16, 0x0303);
+
+    assert_eq!(OddNibbles::A as u32, 0x1111_1111);
+    assert_eq!(OddNibbles::B as u32, 0x1111_1112);
+
+    assert_eq!(EvenWord::A as i8, 0x03);
+    assert_eq!(EvenWord::B as i8, 0x05);
+}
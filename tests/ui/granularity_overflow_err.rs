@@ -0,0 +1,72 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, granularity = "nibble")]
+enum TooBig {
+    Aa,
+    Ab,
+    Ac,
+    Ad,
+    Ae,
+    Af,
+    Ag,
+    Ah,
+    Ai,
+    Aj,
+    Ak,
+    Al,
+    Am,
+    An,
+    Ao,
+    Ap,
+    Aq,
+    Ar,
+    As,
+    At,
+    Au,
+    Av,
+    Aw,
+    Ax,
+    Ay,
+    Az,
+    Ba,
+    Bb,
+    Bc,
+    Bd,
+    Be,
+    Bf,
+    Bg,
+    Bh,
+    Bi,
+    Bj,
+    Bk,
+    Bl,
+    Bm,
+    Bn,
+    Bo,
+    Bp,
+    Bq,
+    Br,
+    Bs,
+    Bt,
+    Bu,
+    Bv,
+    Bw,
+    Bx,
+    By,
+    Bz,
+    Ca,
+    Cb,
+    Cc,
+    Cd,
+    Ce,
+    Cf,
+    Cg,
+    Ch,
+    Ci,
+    Cj,
+    // 62 -- Too Many for 3 index bits per nibble, less the all-zeros and all-ones values
+    Ck,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(weight = 4, granularity = "byte")]
+enum Weighted {
+    A,
+    B,
+}
+
+fn main() {}
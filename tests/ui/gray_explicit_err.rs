@@ -0,0 +1,12 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(gray, exclude_stuck = false)]
+enum NotAdjacent {
+    A,
+    B,
+    C = 0b110,
+    D,
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(gray, exclude_stuck = false)]
+enum Counter {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+#[repr(i16)]
+#[bit_parity(gray(wrap), exclude_stuck = false)]
+enum Wrapping {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+}
+
+#[repr(u8)]
+#[bit_parity(gray, exclude_stuck = false)]
+enum AdjacentOverride {
+    A,
+    B,
+    // the gray code of 6, adjacent to `B`
+    C = 0b101,
+    D,
+}
+
+#[repr(u8)]
+#[bit_parity(gray, allow_explicit_overrides = true, exclude_stuck = false)]
+enum AllowedJump {
+    A,
+    B = 0b110,
+    C,
+}
+
+fn main() {
+    // variant `i` is the `i`-th Gray code, starting from all zeros
+    assert_eq!(Counter::A as u8, 0b000);
+    assert_eq!(Counter::B as u8, 0b001);
+    assert_eq!(Counter::C as u8, 0b011);
+    assert_eq!(Counter::D as u8, 0b010);
+    assert_eq!(Counter::E as u8, 0b110);
+
+    // the two halves of the 8 code cycle, which meet at `0b100`
+    assert_eq!(Wrapping::A as i16, 0b000);
+    assert_eq!(Wrapping::B as i16, 0b001);
+    assert_eq!(Wrapping::C as i16, 0b011);
+    assert_eq!(Wrapping::D as i16, 0b111);
+    assert_eq!(Wrapping::E as i16, 0b101);
+    assert_eq!(Wrapping::F as i16, 0b100);
+
+    assert_eq!(AdjacentOverride::A as u8, 0b000);
+    assert_eq!(AdjacentOverride::B as u8, 0b001);
+    assert_eq!(AdjacentOverride::C as u8, 0b101);
+    assert_eq!(AdjacentOverride::D as u8, 0b100);
+
+    assert_eq!(AllowedJump::A as u8, 0b000);
+    assert_eq!(AllowedJump::B as u8, 0b110);
+    assert_eq!(AllowedJump::C as u8, 0b111);
+}
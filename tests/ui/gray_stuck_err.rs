@@ -0,0 +1,12 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(gray)]
+enum Counter {
+    // the first Gray code is all zeros, and can't be skipped without moving every variant
+    A,
+    B,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(gray(wrap))]
+enum OddLength {
+    A,
+    B,
+    C,
+}
+
+fn main() {}
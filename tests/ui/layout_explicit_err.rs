@@ -0,0 +1,19 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, layout = "parity_msb")]
+enum EvenMsb {
+    A,
+    // index 7 has odd parity, so it needs the top bit
+    B = 0x07,
+}
+
+#[repr(u8)]
+#[bit_parity(odd, layout = "parity_lsb")]
+enum OddLsb {
+    A,
+    // index 1 has odd parity, so it needs the bottom bit cleared
+    B = 0x03,
+}
+
+fn main() {}
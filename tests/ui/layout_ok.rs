@@ -0,0 +1,61 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, layout = "parity_msb", exclude_stuck = false)]
+enum EvenMsb {
+    A,
+    B,
+    C = 0x05,
+    D,
+}
+
+#[repr(u16)]
+#[bit_parity(odd, layout = "parity_lsb")]
+enum OddLsb {
+    A,
+    B,
+    C,
+}
+
+#[repr(i32)]
+#[bit_parity(even, layout = "parity_msb", exclude_stuck = false)]
+enum SignedMsb {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(odd, layout = "parity_msb")]
+enum NotStuck {
+    A,
+    B,
+}
+
+// the parity bit is at the bottom, so it does not depend on the pointer width
+#[repr(usize)]
+#[bit_parity(odd, layout = "parity_lsb")]
+enum PointerLsb {
+    A,
+    B,
+}
+
+fn main() {
+    assert_eq!(EvenMsb::A as u8, 0x00);
+    assert_eq!(EvenMsb::B as u8, 0x81);
+    assert_eq!(EvenMsb::C as u8, 0x05);
+    assert_eq!(EvenMsb::D as u8, 0x06);
+
+    assert_eq!(OddLsb::A as u16, 0x0001);
+    assert_eq!(OddLsb::B as u16, 0x0002);
+    assert_eq!(OddLsb::C as u16, 0x0004);
+
+    assert_eq!(SignedMsb::A as i32, 0x00);
+    assert_eq!(SignedMsb::B as i32, i32::MIN | 0x01);
+
+    // odd parity never gives the all-zeros value, so `exclude_stuck` is left on
+    assert_eq!(NotStuck::A as u8, 0x80);
+    assert_eq!(NotStuck::B as u8, 0x01);
+
+    assert_eq!(PointerLsb::A as usize, 0x01);
+    assert_eq!(PointerLsb::B as usize, 0x02);
+}
@@ -0,0 +1,17 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(berger, layout = "parity_lsb")]
+enum Berger {
+    A,
+    B,
+}
+
+#[repr(u16)]
+#[bit_parity(even, granularity = "byte", layout = "parity_lsb")]
+enum PerByte {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, layout = "parity_msb")]
+enum Skipped {
+    // index 0 is all zeros, and can't be skipped without moving every variant
+    A,
+    B,
+}
+
+fn main() {}
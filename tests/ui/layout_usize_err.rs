@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+// the most significant bit is at a different position on every pointer width
+#[repr(usize)]
+#[bit_parity(even, layout = "parity_msb", exclude_stuck = false)]
+enum Command {
+    Start,
+    Stop,
+}
+
+fn main() {}
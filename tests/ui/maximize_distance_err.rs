@@ -0,0 +1,17 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, maximize_distance)]
+enum Even {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(maximize_distance, min_distance = 3)]
+enum Distance {
+    A,
+    B,
+}
+
+fn main() {}
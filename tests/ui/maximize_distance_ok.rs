@@ -0,0 +1,79 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(maximize_distance)]
+enum Four {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[repr(u32)]
+#[bit_parity(maximize_distance)]
+enum Wide {
+    A,
+    B,
+    C,
+}
+
+#[repr(u16)]
+#[bit_parity(maximize_distance)]
+enum Explicit {
+    A,
+    B = 0x00ff,
+    C,
+}
+
+#[repr(i8)]
+#[bit_parity(maximize_distance)]
+pub enum WithFields {
+    A(u8),
+    B { value: u16 },
+}
+
+/// an enum with the given variants, which must reach the largest distance they can have
+macro_rules! optimum {
+    ($name:ident: $repr:ident, $($variant:ident),+ => $distance:literal) => {
+        #[repr($repr)]
+        #[bit_parity(maximize_distance)]
+        enum $name {
+            $($variant),+
+        }
+
+        const _: () = assert!($name::MIN_DISTANCE == $distance);
+    };
+}
+
+// two values can differ in every bit, and three or four in 2/3 of them
+optimum!(TwoU8: u8, A, B => 8);
+optimum!(ThreeU8: u8, A, B, C => 5);
+optimum!(FourU8: u8, A, B, C, D => 5);
+optimum!(TwoU16: u16, A, B => 16);
+optimum!(ThreeU16: u16, A, B, C => 10);
+optimum!(FourU16: u16, A, B, C, D => 10);
+optimum!(TwoU32: u32, A, B => 32);
+optimum!(ThreeU32: u32, A, B, C => 21);
+optimum!(FourU32: u32, A, B, C, D => 21);
+
+fn main() {
+    // three runs of 3, 3 and 2 bits, where each pair agrees in one run, moved off all zeros
+    assert_eq!(Four::MIN_DISTANCE, 5);
+    assert_eq!(Four::A as u8, 0x01);
+    assert_eq!(Four::B as u8, 0xf9);
+    assert_eq!(Four::C as u8, 0xc6);
+    assert_eq!(Four::D as u8, 0x3e);
+
+    assert_eq!(Wide::MIN_DISTANCE, 21);
+    assert_eq!(Wide::A as u32, 0x0000_0001);
+    assert_eq!(Wide::B as u32, 0xffff_f801);
+    assert_eq!(Wide::C as u32, 0xffc0_07fe);
+
+    // an explicit discriminant is searched around instead
+    assert_eq!(Explicit::MIN_DISTANCE, 10);
+    assert_eq!(Explicit::A as u16, 0x0300);
+    assert_eq!(Explicit::B as u16, 0x00ff);
+    assert_eq!(Explicit::C as u16, 0x7c07);
+
+    assert_eq!(WithFields::MIN_DISTANCE, 8);
+}
@@ -0,0 +1,13 @@
+use enum_parity::bit_parity;
+
+#[repr(u64)]
+#[bit_parity(min_distance = 20)]
+enum TooFar {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(min_distance = 3)]
+enum TooClose {
+    A = 0x07,
+    B,
+    C = 0x0f,
+}
+
+fn main() {}
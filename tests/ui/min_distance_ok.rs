@@ -0,0 +1,46 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(min_distance = 3)]
+enum Distance3 {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[repr(u16)]
+#[bit_parity(even, min_distance = 4)]
+enum EvenDistance4 {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[repr(u8)]
+#[bit_parity(min_distance = 3)]
+enum Distance3Explicit {
+    A,
+    B = 0x70,
+    C,
+    D,
+}
+
+fn main() {
+    assert_eq!(Distance3::A as u8, 0x01);
+    assert_eq!(Distance3::B as u8, 0x06);
+    assert_eq!(Distance3::C as u8, 0x18);
+    assert_eq!(Distance3::D as u8, 0x1f);
+
+    assert_eq!(EvenDistance4::A as u16, 0x03);
+    assert_eq!(EvenDistance4::B as u16, 0x0c);
+    assert_eq!(EvenDistance4::C as u16, 0x30);
+    assert_eq!(EvenDistance4::D as u16, 0x3f);
+
+    // every variant is at least 3 bits away from the explicit `0x70`
+    assert_eq!(Distance3Explicit::A as u8, 0x01);
+    assert_eq!(Distance3Explicit::B as u8, 0x70);
+    assert_eq!(Distance3Explicit::C as u8, 0x77);
+    assert_eq!(Distance3Explicit::D as u8, 0x82);
+}
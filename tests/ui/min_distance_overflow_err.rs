@@ -0,0 +1,13 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(min_distance = 5)]
+enum TooBig {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+fn main() {}
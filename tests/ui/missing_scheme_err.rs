@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(allow_explicit_overrides = true)]
+enum NoParity {
+    A,
+    B,
+}
+
+fn main() {}
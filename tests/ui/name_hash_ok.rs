@@ -0,0 +1,58 @@
+use enum_parity::bit_parity;
+
+#[repr(u32)]
+#[bit_parity(even, assign = "name_hash", key = 0x1234)]
+enum Command {
+    Idle,
+    Start,
+    Stop,
+}
+
+#[repr(u32)]
+#[bit_parity(even, assign = "name_hash", key = 0x1234)]
+enum Reordered {
+    Stop,
+    Idle,
+    Start,
+}
+
+#[repr(u32)]
+#[bit_parity(even, assign = "name_hash", key = 0x1234)]
+enum Taken {
+    Reserved = 0x4491_ce7e,
+    Idle,
+    Stop,
+}
+
+#[repr(u8)]
+#[bit_parity(odd, assign = "name_hash", key = 7, min_distance = 3)]
+enum Small {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+}
+
+fn main() {
+    assert_eq!(Command::Idle as u32, 0x4491_ce7e);
+    assert_eq!(Command::Start as u32, 0x5891_abfb);
+    assert_eq!(Command::Stop as u32, 0xeafa_c277);
+
+    // the same names get the same discriminants in any order
+    assert_eq!(Reordered::Idle as u32, 0x4491_ce7e);
+    assert_eq!(Reordered::Start as u32, 0x5891_abfb);
+    assert_eq!(Reordered::Stop as u32, 0xeafa_c277);
+
+    // a taken value is hashed again
+    assert_eq!(Taken::Idle as u32, 0x92e5_cde9);
+    assert_eq!(Taken::Stop as u32, 0xeafa_c277);
+
+    assert_eq!(Small::A as u8, 0x97);
+    assert_eq!(Small::B as u8, 0x52);
+    assert_eq!(Small::C as u8, 0x49);
+    assert_eq!(Small::D as u8, 0xc4);
+    assert_eq!(Small::E as u8, 0xa2);
+    assert_eq!(Small::F as u8, 0xfe);
+}
@@ -0,0 +1,13 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, assign = "name_hash", key = 0x1234, min_distance = 5)]
+enum Command {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u32)]
+#[bit_parity(secded, assign = "name_hash", key = 0x1234)]
+enum Command {
+    Idle,
+    Start,
+}
+
+fn main() {}
@@ -0,0 +1,104 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Light {
+    Off,
+    Dim,
+    #[cfg(any())]
+    Skipped,
+    Bright,
+}
+
+/// a lookup table that is built at compile time
+static NAMES: [(u8, &str); Light::VARIANTS.len()] = [
+    (Light::DISCRIMINANTS[0], "off"),
+    (Light::DISCRIMINANTS[1], "dim"),
+    (Light::Bright.to_repr(), "bright"),
+];
+
+const DIM: Light = match Light::from_repr(0x05) {
+    Some(light) => light,
+    None => panic!("not a variant"),
+};
+const _: () = assert!(Light::from_repr(0x06).is_none());
+// every even value above the skipped variant, other than the stuck ones
+const _: () = assert!(Light::REMAINING_CAPACITY == 122);
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum Reserved {
+    A = 0x30,
+    B = 0x05,
+    C,
+}
+
+#[repr(u8)]
+#[bit_parity(secded)]
+enum Secded {
+    A,
+    B,
+    C,
+}
+
+#[repr(i8)]
+#[bit_parity(odd)]
+enum Signed {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(min_distance = 3)]
+enum Distance {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[repr(u16)]
+#[bit_parity(even, assign = "name_hash", key = 7)]
+enum Hashed {
+    A,
+    B,
+}
+
+#[repr(u128)]
+#[bit_parity(even)]
+enum Wide {
+    A,
+}
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum WithFields {
+    A,
+    B(u8),
+}
+
+fn main() {
+    assert_eq!(DIM, Light::Dim);
+    assert_eq!(Light::VARIANTS, [Light::Off, Light::Dim, Light::Bright]);
+    assert_eq!(Light::DISCRIMINANTS, [0x03, 0x05, 0x09]);
+    assert_eq!(NAMES[2], (0x09, "bright"));
+    assert!(Light::VARIANTS.iter().all(|&light| Light::from_repr(light.to_repr()) == Some(light)));
+
+    // a further variant would collide with `A`
+    assert_eq!(Reserved::DISCRIMINANTS, [0x30, 0x05, 0x06]);
+    assert_eq!(Reserved::REMAINING_CAPACITY, 20);
+
+    // 16 codewords, less the three taken and the two stuck ones
+    assert_eq!(Secded::REMAINING_CAPACITY, 11);
+    assert_eq!(Signed::REMAINING_CAPACITY, 126);
+    assert_eq!(Distance::REMAINING_CAPACITY, 12);
+    // a hashed variant may take any free value
+    assert_eq!(Hashed::REMAINING_CAPACITY, (1 << 15) - 4);
+    assert_eq!(Wide::REMAINING_CAPACITY, (1 << 127) - 3);
+
+    // only the consts that construct variants need a fieldless enum
+    assert_eq!(WithFields::REMAINING_CAPACITY, 124);
+    let _ = (WithFields::A, WithFields::B(0), Reserved::B, Reserved::C, Secded::A, Secded::B, Secded::C);
+    let _ = (Signed::A, Signed::B, Distance::A, Distance::B, Distance::C, Distance::D, Hashed::A, Hashed::B, Wide::A);
+}
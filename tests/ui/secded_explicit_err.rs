@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(secded)]
+enum NotACodeword {
+    A,
+    B = 0x0e,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use enum_parity::{Uncorrectable, bit_parity};
+
+#[repr(u8)]
+#[bit_parity(secded)]
+#[derive(Debug, Eq, PartialEq)]
+enum SecdedU8 {
+    A,
+    B,
+    C,
+    D = 0xcc,
+    E,
+}
+
+#[repr(i64)]
+#[bit_parity(secded)]
+#[derive(Debug, Eq, PartialEq)]
+pub enum SecdedI64 {
+    A,
+    B,
+    #[cfg(any())]
+    Skipped,
+    C,
+}
+
+fn main() {
+    assert_eq!(SecdedU8::A as u8, 0x0f);
+    assert_eq!(SecdedU8::B as u8, 0x33);
+    assert_eq!(SecdedU8::C as u8, 0x3c);
+    assert_eq!(SecdedU8::D as u8, 0xcc);
+    assert_eq!(SecdedU8::E as u8, 0xf0);
+
+    for bit in 0..8 {
+        assert_eq!(SecdedU8::correct(0x3c ^ 1 << bit), Ok(SecdedU8::C));
+    }
+    for bit in 0..7 {
+        assert_eq!(SecdedU8::correct(0x3c ^ 3 << bit), Err(Uncorrectable));
+    }
+    // a valid codeword, that is not assigned to a variant because it is all zeros
+    assert_eq!(SecdedU8::correct(0x00), Err(Uncorrectable));
+
+    assert_eq!(SecdedI64::correct(SecdedI64::C as i64 ^ i64::MIN), Ok(SecdedI64::C));
+    assert_eq!(SecdedI64::correct(SecdedI64::B as i64 ^ 0x11), Err(Uncorrectable));
+
+    const CORRECTED: Result<SecdedU8, Uncorrectable> = SecdedU8::correct(0x0e);
+    assert_eq!(CORRECTED, Ok(SecdedU8::A));
+}
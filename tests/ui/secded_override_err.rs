@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(secded, allow_explicit_overrides = true)]
+enum NearCodeword {
+    X,
+    Y = 0x0e,
+    Z,
+}
+
+fn main() {}
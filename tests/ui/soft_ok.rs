@@ -0,0 +1,58 @@
+use enum_parity::{Decoded, bit_parity};
+
+#[repr(u8)]
+#[bit_parity(even)]
+#[derive(Debug, Eq, PartialEq)]
+enum Even {
+    A,
+    B,
+    #[cfg(any())]
+    Skipped,
+    C,
+    D,
+}
+
+#[repr(i16)]
+#[bit_parity(secded)]
+#[derive(Debug, Eq, PartialEq)]
+enum Single {
+    A,
+}
+
+// as many bits as a pointer on the target, whatever the host's pointer width
+#[repr(usize)]
+#[bit_parity(odd)]
+#[derive(Debug, Eq, PartialEq)]
+enum Pointer {
+    A,
+    B,
+}
+
+/// confident bits for `raw`, except for bit `weak`, which only barely leans the wrong way
+fn soft(raw: u8, weak: usize) -> [f32; 8] {
+    core::array::from_fn(|bit| {
+        let llr = if bit == weak { -0.5 } else { 4.0 };
+        if raw >> bit & 1 == 1 { -llr } else { llr }
+    })
+}
+
+const ALL_CLEAR: (Even, f32) = Even::decode_soft(&[1.0; 8]);
+
+fn main() {
+    // `A` is 0x03, and `B` is the nearest other variant at 2 bits away
+    assert_eq!(Even::decode_soft(&soft(0x03, 8)), (Even::A, 16.0));
+    assert_eq!(Even::decode_soft(&soft(Even::D as u8, 8)).0, Even::D);
+
+    // the hard bits are 0x01, which is equally near to `A` and `B`, but the soft bits are not
+    assert!(matches!(Even::decode_nearest(0x01), Decoded::Ambiguous { .. }));
+    assert_eq!(Even::decode_soft(&soft(0x03, 1)), (Even::A, 7.0));
+
+    // `A` and `B` both have two bits set, so they tie, and the first one wins
+    assert_eq!(ALL_CLEAR, (Even::A, 0.0));
+
+    assert_eq!(Single::decode_soft(&[1.0; 16]), (Single::A, f32::INFINITY));
+
+    let mut llrs = [4.0; usize::BITS as usize];
+    llrs[1] = -4.0;
+    assert_eq!(Pointer::decode_soft(&llrs), (Pointer::B, 16.0));
+}
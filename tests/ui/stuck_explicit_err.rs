@@ -0,0 +1,17 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even)]
+enum AllZeros {
+    A = 0x00,
+    B,
+}
+
+#[repr(u32)]
+#[bit_parity(odd, allow_explicit_overrides = true)]
+enum AllOnes {
+    A,
+    B = 0xffff_ffff,
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(weight = 8, exclude_stuck = false)]
+enum AllOnes {
+    A,
+}
+
+#[repr(i16)]
+#[bit_parity(odd, allow_explicit_overrides = true)]
+enum Overridden {
+    A,
+    // the override is allowed, only the stuck values are not
+    B = 0x03,
+    C,
+}
+
+fn main() {
+    assert_eq!(AllOnes::A as u8, 0xff);
+
+    assert_eq!(Overridden::A as i16, 0x01);
+    assert_eq!(Overridden::B as i16, 0x03);
+    assert_eq!(Overridden::C as i16, 0x04);
+}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(weight = 8)]
+enum AllOnes {
+    // 0 -- the only value with 8 bits set is all ones
+    A,
+}
+
+fn main() {}
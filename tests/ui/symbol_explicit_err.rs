@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(symbol_distance = 3)]
+enum Sensor {
+    Temperature,
+    Pressure = 0x2c4,
+    Humidity,
+}
+
+fn main() {}
@@ -0,0 +1,57 @@
+use enum_parity::{Uncorrectable, bit_parity};
+
+#[repr(u16)]
+#[bit_parity(symbol_distance = 3)]
+#[derive(Debug, Eq, PartialEq)]
+enum Sensor {
+    Temperature,
+    Pressure,
+    Humidity,
+    Flow,
+}
+
+#[repr(u32)]
+#[bit_parity(symbol_distance = 5, symbol_bits = 4)]
+#[derive(Debug, Eq, PartialEq)]
+pub enum Mode {
+    Off,
+    Standby,
+    #[cfg(any())]
+    Skipped,
+    Run,
+}
+
+#[repr(i64)]
+#[bit_parity(symbol_distance = 3, symbol_bits = 8)]
+#[derive(Debug, Eq, PartialEq)]
+enum Frame {
+    Start,
+    End,
+}
+
+fn main() {
+    assert_eq!(Sensor::Temperature as u16, 0x168);
+    assert_eq!(Sensor::Pressure as u16, 0x2c3);
+    assert_eq!(Sensor::Humidity as u16, 0x3ab);
+    assert_eq!(Sensor::Flow as u16, 0x4b6);
+
+    for nibble in 0..4 {
+        for corruption in 1..16 {
+            let raw = 0x3ab ^ corruption << (4 * nibble);
+            assert_eq!(Sensor::correct(raw), Ok(Sensor::Humidity));
+        }
+    }
+    // two corrupted nibbles are more than one
+    assert_eq!(Sensor::correct(0x3ab ^ 0x0110), Err(Uncorrectable));
+
+    assert_eq!(Mode::Off as u32, 0x1dc87);
+    assert_eq!(Mode::Standby as u32, 0x29b3e);
+    assert_eq!(Mode::Run as u32, 0x4156f);
+    assert_eq!(Mode::correct(0x4156f ^ 0x0f00_0100), Ok(Mode::Run));
+
+    assert_eq!(Frame::Start as i64, 0x10608);
+    assert_eq!(Frame::correct(Frame::End as i64 ^ -1 << 56), Ok(Frame::End));
+
+    const CORRECTED: Result<Sensor, Uncorrectable> = Sensor::correct(0x2c3 ^ 0xf000);
+    assert_eq!(CORRECTED, Ok(Sensor::Pressure));
+}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(symbol_distance = 3)]
+enum Sensor {
+    Temperature,
+    Pressure,
+}
+
+fn main() {}
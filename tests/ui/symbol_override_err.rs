@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(symbol_distance = 3, allow_explicit_overrides = true)]
+enum Sensor {
+    Temperature,
+    Pressure = 0x16b,
+    Humidity,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(even, symbol_distance = 3)]
+enum Sensor {
+    Temperature,
+    Pressure,
+}
+
+#[repr(u16)]
+#[bit_parity(symbol_distance = 0)]
+enum Mode {
+    Off,
+    On,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, tag = 0b101, tag_bits = 3, allow_explicit_overrides = true)]
+enum CommandId {
+    Start,
+    Stop = 0x63,
+}
+
+fn main() {}
@@ -0,0 +1,55 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, tag = 0b101, tag_bits = 3)]
+#[derive(Debug, Eq, PartialEq)]
+enum CommandId {
+    Start,
+    Stop,
+    Reset = 0xb4,
+    Abort,
+}
+
+#[repr(u8)]
+#[bit_parity(even, tag = 0b011, tag_bits = 3)]
+#[derive(Debug, Eq, PartialEq)]
+enum StatusId {
+    Ok,
+    Busy,
+}
+
+#[repr(i8)]
+#[bit_parity(odd, tag = 0b110, tag_bits = 3)]
+#[derive(Debug, Eq, PartialEq)]
+enum Signed {
+    A,
+    #[cfg(any())]
+    Skipped,
+    B,
+}
+
+fn main() {
+    assert_eq!(CommandId::Start as u8, 0xa0);
+    assert_eq!(CommandId::Stop as u8, 0xa3);
+    assert_eq!(CommandId::Reset as u8, 0xb4);
+    assert_eq!(CommandId::Abort as u8, 0xb7);
+
+    assert_eq!(StatusId::Ok as u8, 0x60);
+    assert_eq!(StatusId::Busy as u8, 0x63);
+
+    assert_eq!(CommandId::decode(0xa3), Some(CommandId::Stop));
+    // a status, or a value without any tag, is never a command
+    assert_eq!(CommandId::decode(StatusId::Busy as u8), None);
+    assert_eq!(CommandId::decode(0x03), None);
+    assert_eq!(StatusId::decode(CommandId::Stop as u8), None);
+    // the right tag, but not a discriminant
+    assert_eq!(CommandId::decode(0xa5), None);
+
+    assert_eq!(Signed::A as i8 as u8, 0xc1);
+    assert_eq!(Signed::B as i8 as u8, 0xc4);
+    assert_eq!(Signed::decode(Signed::B as i8), Some(Signed::B));
+    assert_eq!(Signed::decode(0x01), None);
+
+    const DECODED: Option<StatusId> = StatusId::decode(0x60);
+    assert_eq!(DECODED, Some(StatusId::Ok));
+}
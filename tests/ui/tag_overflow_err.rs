@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(even, tag = 0b111111, tag_bits = 6)]
+enum CommandId {
+    Start,
+    Stop,
+}
+
+fn main() {}
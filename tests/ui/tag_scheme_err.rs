@@ -0,0 +1,10 @@
+use enum_parity::bit_parity;
+
+#[repr(u16)]
+#[bit_parity(crc = "crc4-itu", tag = 0b101, tag_bits = 3)]
+enum CommandId {
+    Start,
+    Stop,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+// the tag is in the high bits, which are at a different position on every pointer width
+#[repr(usize)]
+#[bit_parity(even, tag = 0b101, tag_bits = 3)]
+enum CommandId {
+    Start,
+    Stop,
+}
+
+fn main() {}
@@ -0,0 +1,158 @@
+use enum_parity::{ParityError, bit_parity};
+
+#[repr(u8)]
+#[bit_parity(even)]
+#[derive(Debug, Eq, PartialEq)]
+enum Even {
+    A,
+    B,
+    #[cfg(any())]
+    Skipped,
+    C,
+}
+
+#[repr(i8)]
+#[bit_parity(odd)]
+#[derive(Debug, Eq, PartialEq)]
+enum Odd {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(berger)]
+#[derive(Debug, Eq, PartialEq)]
+enum Berger {
+    A,
+    B,
+}
+
+#[repr(i8)]
+#[bit_parity(berger)]
+#[derive(Debug, Eq, PartialEq)]
+enum SignedBerger {
+    A,
+    B,
+}
+
+#[repr(u16)]
+#[bit_parity(crc = "crc4-itu")]
+#[derive(Debug, Eq, PartialEq)]
+enum Crc {
+    A,
+    B,
+}
+
+#[repr(u32)]
+#[bit_parity(bch(t = 2))]
+#[derive(Debug, Eq, PartialEq)]
+enum Bch {
+    A,
+    B,
+}
+
+#[repr(u16)]
+#[bit_parity(complement)]
+#[derive(Debug, Eq, PartialEq)]
+enum Complement {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(gray(wrap), exclude_stuck = false)]
+#[derive(Debug, Eq, PartialEq)]
+enum Ring {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+}
+
+#[repr(u16)]
+#[bit_parity(check_digit = "verhoeff")]
+#[derive(Debug, Eq, PartialEq)]
+enum Fault {
+    A,
+    B,
+}
+
+#[repr(u8)]
+#[bit_parity(min_distance = 3)]
+#[derive(Debug, Eq, PartialEq)]
+enum Distance {
+    A,
+    B,
+}
+
+#[derive(Debug)]
+#[repr(u8)]
+#[bit_parity(even)]
+enum WithFields {
+    A,
+    B(u8),
+}
+
+/// the number of values in `values` that `TryFrom` rejects with each error
+fn count<T, R>(values: impl Iterator<Item = R>) -> (usize, usize)
+where
+    T: TryFrom<R, Error = ParityError<R>>,
+{
+    values.fold((0, 0), |(invalid, no_variant), raw| match T::try_from(raw) {
+        Ok(_) => (invalid, no_variant),
+        Err(ParityError::InvalidParity(_)) => (invalid + 1, no_variant),
+        Err(ParityError::NoVariant(_)) => (invalid, no_variant + 1),
+    })
+}
+
+fn main() {
+    assert_eq!(Even::try_from(0x03), Ok(Even::A));
+    assert_eq!(Even::try_from(0x09), Ok(Even::C));
+    assert_eq!(Even::try_from(0x02), Err(ParityError::InvalidParity(0x02)));
+    assert_eq!(Even::try_from(0x0a), Err(ParityError::NoVariant(0x0a)));
+    assert_eq!(Even::try_from(0x0a).unwrap_err().raw(), 0x0a);
+    assert_eq!(count::<Even, u8>(0..=255), (128, 125));
+    assert_eq!(
+        ParityError::InvalidParity(0x02_u8).to_string(),
+        "discriminant 2 failed its parity check"
+    );
+    assert_eq!(
+        ParityError::NoVariant(0x05_u8).to_string(),
+        "discriminant 5 passed its parity check, but is not a variant"
+    );
+
+    assert_eq!(Odd::try_from(0x02), Ok(Odd::B));
+    assert_eq!(Odd::try_from(-128), Err(ParityError::NoVariant(-128)));
+    assert_eq!(Odd::try_from(-1), Err(ParityError::InvalidParity(-1)));
+    assert_eq!(count::<Odd, i8>(-128..=127), (128, 126));
+
+    // only the 32 Berger codewords pass
+    assert_eq!(count::<Berger, u8>(0..=255), (224, 30));
+    // the count of zeros is read from the high bits as they are, not sign extended
+    assert_eq!(SignedBerger::try_from(0x82_u8 as i8), Err(ParityError::NoVariant(-126)));
+    assert_eq!(count::<SignedBerger, i8>(-128..=127), (224, 30));
+
+    assert_eq!(Crc::try_from(Crc::B as u16 ^ 0x10), Err(ParityError::InvalidParity(Crc::B as u16 ^ 0x10)));
+    assert_eq!(count::<Crc, u16>(0..=u16::MAX), ((1 << 16) - (1 << 12), (1 << 12) - 2));
+
+    assert_eq!(Bch::try_from(Bch::A as u32 ^ 1), Err(ParityError::InvalidParity(Bch::A as u32 ^ 1)));
+    assert_eq!(Bch::try_from(0), Err(ParityError::NoVariant(0)));
+
+    assert_eq!(Complement::try_from(0xf00f), Err(ParityError::NoVariant(0xf00f)));
+    assert_eq!(Complement::try_from(0xf00e), Err(ParityError::InvalidParity(0xf00e)));
+
+    // the two codes between the halves of the ring are skipped
+    assert_eq!(Ring::try_from(Ring::C as u8), Ok(Ring::C));
+    assert_eq!(count::<Ring, u8>(0..8), (2, 0));
+
+    assert_eq!(Fault::try_from(Fault::B as u16), Ok(Fault::B));
+    assert_eq!(Fault::try_from(2363), Err(ParityError::NoVariant(2363)));
+    assert_eq!(Fault::try_from(2336), Err(ParityError::InvalidParity(2336)));
+
+    // without a parity, every value passes
+    assert_eq!(count::<Distance, u8>(0..=255), (0, 254));
+
+    let _ = WithFields::B(0);
+}
@@ -0,0 +1,11 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(weight = 3)]
+enum WrongWeight {
+    A,
+    B = 0x0f,
+    C,
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(weight = 2)]
+enum TwoOfEight {
+    A,
+    B,
+    C,
+    D = 0x30,
+    E,
+}
+
+#[repr(u64)]
+#[bit_parity(weight = 32)]
+enum HalfOfSixtyFour {
+    A,
+    B,
+}
+
+#[repr(i8)]
+#[bit_parity(weight = 4, min_distance = 4)]
+enum FourOfEight {
+    A,
+    B,
+    C,
+}
+
+fn main() {
+    assert_eq!(TwoOfEight::A as u8, 0x03);
+    assert_eq!(TwoOfEight::B as u8, 0x05);
+    assert_eq!(TwoOfEight::C as u8, 0x06);
+    assert_eq!(TwoOfEight::D as u8, 0x30);
+    assert_eq!(TwoOfEight::E as u8, 0x41);
+
+    assert_eq!(HalfOfSixtyFour::A as u64, 0xffff_ffff);
+    assert_eq!(HalfOfSixtyFour::B as u64, 0x1_7fff_ffff);
+
+    assert_eq!(FourOfEight::A as i8, 0x0f);
+    assert_eq!(FourOfEight::B as i8, 0x33);
+    assert_eq!(FourOfEight::C as i8, 0x3c);
+}
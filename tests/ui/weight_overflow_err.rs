@@ -0,0 +1,17 @@
+use enum_parity::bit_parity;
+
+#[repr(u8)]
+#[bit_parity(weight = 7)]
+enum TooBig {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+}
+
+fn main() {}